@@ -14,6 +14,9 @@ const AGENT_SEED = Buffer.from("agent");
 const TASK_SEED = Buffer.from("task");
 const CLAIM_SEED = Buffer.from("claim");
 const INVITE_SEED = Buffer.from("invite");
+const PREREQ_SEED = Buffer.from("prereqs");
+const ECONOMY_CONFIG_SEED = Buffer.from("economy_config");
+const EMISSION_POLICY_SEED = Buffer.from("emission_policy");
 
 export function loadKeypair(filePath: string): Keypair {
   const raw = fs.readFileSync(filePath, "utf8");
@@ -87,6 +90,14 @@ export function getProtocolPda(programId: PublicKey): PublicKey {
   return PublicKey.findProgramAddressSync([PROTOCOL_SEED], programId)[0];
 }
 
+export function getEconomyConfigPda(programId: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync([ECONOMY_CONFIG_SEED], programId)[0];
+}
+
+export function getEmissionPolicyPda(programId: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync([EMISSION_POLICY_SEED], programId)[0];
+}
+
 export function getAgentPda(programId: PublicKey, agent: PublicKey): PublicKey {
   return PublicKey.findProgramAddressSync(
     [AGENT_SEED, agent.toBuffer()],
@@ -101,6 +112,13 @@ export function getTaskPda(programId: PublicKey, taskId: number): PublicKey {
   )[0];
 }
 
+export function getTaskPrerequisitesPda(programId: PublicKey, taskId: number): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [PREREQ_SEED, taskIdBytes(taskId)],
+    programId
+  )[0];
+}
+
 export function getClaimPda(
   programId: PublicKey,
   taskId: number,
@@ -11,10 +11,13 @@ import {
   fromFixedBytes,
   getAgentPda,
   getClaimPda,
+  getEconomyConfigPda,
+  getEmissionPolicyPda,
   getInvitePda,
   getProgram,
   getProtocolPda,
   getTaskPda,
+  getTaskPrerequisitesPda,
   toFixedBytes,
 } from "./client.js";
 import { provisionPrivyWallet } from "./privy.js";
@@ -61,6 +64,13 @@ export class SolanaAdapter implements ChainAdapter {
     return (prov.wallet as anchor.Wallet).publicKey;
   }
 
+  /** Emission policy account for reward instructions, once the protocol has one. */
+  private async emissionPolicy(): Promise<anchor.web3.PublicKey | null> {
+    const p = await this.program();
+    const protocol = await (p.account as any).protocolState.fetch(getProtocolPda(p.programId));
+    return protocol.emissionPolicyActive ? getEmissionPolicyPda(p.programId) : null;
+  }
+
   async getWalletAddress(): Promise<string> {
     return (await this.pubkey()).toBase58();
   }
@@ -196,6 +206,8 @@ export class SolanaAdapter implements ChainAdapter {
       .registerAgent()
       .accounts({
         protocol: protocolPda,
+        economyConfig: getEconomyConfigPda(p.programId),
+        emissionPolicy: await this.emissionPolicy(),
         agentAccount: agentPda,
         agent: pk,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -224,6 +236,8 @@ export class SolanaAdapter implements ChainAdapter {
       .registerAgentWithInvite(Array.from(inviterPubkey.toBuffer()))
       .accounts({
         protocol: protocolPda,
+        economyConfig: getEconomyConfigPda(p.programId),
+        emissionPolicy: await this.emissionPolicy(),
         agentAccount: agentPda,
         inviterAgent: inviterAgentPda,
         inviteRecord: invitePda,
@@ -287,6 +301,8 @@ export class SolanaAdapter implements ChainAdapter {
       .submitProof(taskId, toFixedBytes(proofCid, 64))
       .accounts({
         protocol: protocolPda,
+        emissionPolicy: await this.emissionPolicy(),
+        economyConfig: getEconomyConfigPda(p.programId),
         task: taskPda,
         agentAccount: agentPda,
         claim: claimPda,
@@ -294,19 +310,37 @@ export class SolanaAdapter implements ChainAdapter {
         systemProgram: anchor.web3.SystemProgram.programId,
       });
 
+    // Same order validate_submission reads them: the required_task_id claim,
+    // then the prerequisite set followed by one claim PDA per listed task.
+    const remainingAccounts: anchor.web3.AccountMeta[] = [];
     if (task.requiredTaskId !== NO_PREREQ_TASK_ID) {
       const prereqClaimPda = getClaimPda(p.programId, task.requiredTaskId, pk);
       const prereqInfo = await prov.connection.getAccountInfo(prereqClaimPda);
       if (!prereqInfo) {
         throw new Error(`Task requires completing task ${task.requiredTaskId} first`);
       }
-      submitBuilder.remainingAccounts([
-        { pubkey: prereqClaimPda, isWritable: false, isSigner: false },
-      ]);
+      remainingAccounts.push({ pubkey: prereqClaimPda, isWritable: false, isSigner: false });
+    }
+    if (task.hasPrerequisiteSet) {
+      const prerequisitesPda = getTaskPrerequisitesPda(p.programId, taskId);
+      const prerequisites = await (p.account as any).taskPrerequisites.fetch(prerequisitesPda);
+      remainingAccounts.push({ pubkey: prerequisitesPda, isWritable: false, isSigner: false });
+      // Unmet entries are passed too; the program counts them as not completed.
+      for (const requiredTaskId of prerequisites.taskIds.slice(0, prerequisites.count)) {
+        remainingAccounts.push({
+          pubkey: getClaimPda(p.programId, requiredTaskId, pk),
+          isWritable: false,
+          isSigner: false,
+        });
+      }
+    }
+    if (remainingAccounts.length > 0) {
+      submitBuilder.remainingAccounts(remainingAccounts);
     }
 
     await submitBuilder.rpc();
-    return { clipsAwarded: task.rewardClips.toNumber() };
+    const claim = await (p.account as any).claimRecord.fetch(claimPda);
+    return { clipsAwarded: claim.clipsAwarded.toNumber() };
   }
 
   async provisionWallet(): Promise<void> {
@@ -3,14 +3,118 @@ pub const AGENT_SEED: &[u8] = b"agent";
 pub const TASK_SEED: &[u8] = b"task";
 pub const CLAIM_SEED: &[u8] = b"claim";
 pub const INVITE_SEED: &[u8] = b"invite";
+pub const PUBLISHER_SEED: &[u8] = b"publisher";
+pub const PROGRESS_SEED: &[u8] = b"progress";
+pub const PREREQ_SEED: &[u8] = b"prereqs";
+pub const DISPUTE_CONFIG_SEED: &[u8] = b"dispute_config";
+pub const CHALLENGE_SEED: &[u8] = b"challenge";
+pub const FLAG_SEED: &[u8] = b"flag";
+pub const SLASH_SEED: &[u8] = b"slash";
+pub const APPEAL_SEED: &[u8] = b"appeal";
+pub const TIER_CONFIG_SEED: &[u8] = b"tier_config";
+pub const ECONOMY_CONFIG_SEED: &[u8] = b"economy_config";
+pub const EMISSION_POLICY_SEED: &[u8] = b"emission_policy";
+pub const REWARD_EVENT_SEED: &[u8] = b"reward_event";
+pub const TASK_TOMBSTONE_SEED: &[u8] = b"task_tombstone";
+pub const REJECTION_SEED: &[u8] = b"rejection";
 pub const NO_PREREQ_TASK_ID: u32 = u32::MAX;
+pub const MAX_PREREQUISITES: usize = 8;
+pub const MAX_PREREQ_CHAIN_DEPTH: usize = 16;
+pub const PREREQ_MODE_ALL: u8 = 0;
+pub const PREREQ_MODE_ANY: u8 = 1;
+
+// `TaskRecord.verification_mode`
+pub const VERIFICATION_INSTANT: u8 = 0;
+pub const VERIFICATION_OPTIMISTIC: u8 = 1;
+pub const VERIFICATION_VALIDATOR: u8 = 2;
+
+// `TaskRecord.reward_mode`
+pub const REWARD_MODE_FIXED: u8 = 0;
+pub const REWARD_MODE_BASE_UNITS: u8 = 1;
+
+// `TaskRecord.reward_curve`
+pub const REWARD_CURVE_NONE: u8 = 0;
+pub const REWARD_CURVE_LINEAR: u8 = 1;
+pub const REWARD_CURVE_EXPONENTIAL: u8 = 2;
+
+// `TaskRecord.reward_curve_basis`
+pub const REWARD_CURVE_BY_CLAIMS: u8 = 0;
+pub const REWARD_CURVE_BY_TIME: u8 = 1;
+
+// `ClaimRecord.status`; zero keeps claims written before statuses existed approved.
+pub const CLAIM_STATUS_APPROVED: u8 = 0;
+pub const CLAIM_STATUS_PENDING: u8 = 1;
+pub const CLAIM_STATUS_AWAITING_VALIDATOR: u8 = 2;
+pub const CLAIM_STATUS_REVOKED: u8 = 3;
+
+// `ChallengeRecord.status`
+pub const CHALLENGE_STATUS_OPEN: u8 = 0;
+pub const CHALLENGE_STATUS_UPHELD: u8 = 1;
+pub const CHALLENGE_STATUS_DISMISSED: u8 = 2;
+
+// `AppealRecord.subject_kind`
+pub const APPEAL_SUBJECT_SLASH: u8 = 0;
+pub const APPEAL_SUBJECT_CLAIM: u8 = 1;
+pub const APPEAL_SUBJECT_REJECTION: u8 = 2;
+
+// `AppealRecord.status`
+pub const APPEAL_STATUS_OPEN: u8 = 0;
+pub const APPEAL_STATUS_UPHELD: u8 = 1;
+pub const APPEAL_STATUS_DENIED: u8 = 2;
+
+// `AgentAccount.efficiency_tier` ladder; names and perks live off-chain.
+pub const TIER_INTERN: u8 = 0;
+pub const TIER_CLIPPER: u8 = 1;
+pub const TIER_BINDER: u8 = 2;
+pub const TIER_ARCHIVIST: u8 = 3;
+pub const TIER_FORGER: u8 = 4;
+pub const TIER_ARCHITECT: u8 = 5;
+pub const TIER_COUNT: usize = 6;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// `EconomyConfig` values written by `initialize`; they match the original
+// hardcoded airdrop and invite rewards (1x, 1.5x and 0.5x the base unit).
+pub const DEFAULT_REGISTRATION_AIRDROP_BPS: u16 = 10_000;
+pub const DEFAULT_INVITEE_REWARD_BPS: u16 = 15_000;
+pub const DEFAULT_INVITER_REWARD_BPS: u16 = 5_000;
+pub const DEFAULT_TIER_REWARD_MULTIPLIER_BPS: u16 = 10_000;
+
+pub const MIN_FLAGGER_TIER: u8 = TIER_BINDER;
+
+// Bits in `ProtocolState.feature_flags`; a set bit disables the feature.
+pub const FEATURE_INVITE_REGISTRATION_DISABLED: u32 = 1 << 0;
+pub const FEATURE_PROOF_SUBMISSION_DISABLED: u32 = 1 << 1;
+pub const FEATURE_INVITE_CREATION_DISABLED: u32 = 1 << 2;
+pub const FEATURE_FLAGS_MASK: u32 = FEATURE_INVITE_REGISTRATION_DISABLED
+    | FEATURE_PROOF_SUBMISSION_DISABLED
+    | FEATURE_INVITE_CREATION_DISABLED;
+
+// Bits in `PublisherRole.permissions`.
+pub const PUBLISHER_CAN_CREATE: u8 = 1 << 0;
+pub const PUBLISHER_CAN_DEACTIVATE: u8 = 1 << 1;
+pub const PUBLISHER_PERMISSIONS_MASK: u8 = PUBLISHER_CAN_CREATE | PUBLISHER_CAN_DEACTIVATE;
 
 pub const ACCOUNT_LAYOUT_V1: u8 = 1;
 
 // Keep reserved bytes at the tail of each account to absorb future schema changes
 // without immediate realloc migrations.
-pub const PROTOCOL_RESERVED_BYTES: usize = 64;
-pub const AGENT_RESERVED_BYTES: usize = 88;
-pub const TASK_RESERVED_BYTES: usize = 128;
-pub const CLAIM_RESERVED_BYTES: usize = 64;
+pub const PROTOCOL_RESERVED_BYTES: usize = 11;
+pub const AGENT_RESERVED_BYTES: usize = 48;
+pub const TASK_RESERVED_BYTES: usize = 42;
+pub const CLAIM_RESERVED_BYTES: usize = 38;
 pub const INVITE_RESERVED_BYTES: usize = 64;
+pub const PUBLISHER_RESERVED_BYTES: usize = 64;
+pub const PROGRESS_RESERVED_BYTES: usize = 32;
+pub const PREREQ_RESERVED_BYTES: usize = 32;
+pub const DISPUTE_CONFIG_RESERVED_BYTES: usize = 62;
+pub const CHALLENGE_RESERVED_BYTES: usize = 32;
+pub const FLAG_RESERVED_BYTES: usize = 32;
+pub const SLASH_RESERVED_BYTES: usize = 32;
+pub const APPEAL_RESERVED_BYTES: usize = 32;
+pub const TIER_CONFIG_RESERVED_BYTES: usize = 40;
+pub const ECONOMY_CONFIG_RESERVED_BYTES: usize = 58;
+pub const EMISSION_POLICY_RESERVED_BYTES: usize = 16;
+pub const REWARD_EVENT_RESERVED_BYTES: usize = 32;
+pub const TASK_TOMBSTONE_RESERVED_BYTES: usize = 16;
+pub const REJECTION_RESERVED_BYTES: usize = 32;
@@ -24,4 +24,126 @@ pub enum ErrorCode {
     InviteInactive,
     #[msg("Self-referral is not allowed")]
     SelfReferralNotAllowed,
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+    #[msg("Unknown feature flag bits")]
+    InvalidFeatureFlags,
+    #[msg("Invite registration is disabled")]
+    InviteRegistrationDisabled,
+    #[msg("Proof submission is disabled")]
+    ProofSubmissionDisabled,
+    #[msg("Invite creation is disabled")]
+    InviteCreationDisabled,
+    #[msg("Invalid publisher permissions")]
+    InvalidPublisherPermissions,
+    #[msg("Invalid publisher task id range")]
+    InvalidPublisherTaskRange,
+    #[msg("Task id is outside the publisher's allowed range")]
+    TaskIdOutsidePublisherRange,
+    #[msg("max_claims cannot be lower than current_claims")]
+    MaxClaimsBelowCurrentClaims,
+    #[msg("Task must be deactivated first")]
+    TaskStillActive,
+    #[msg("Creator account does not match task creator")]
+    InvalidTaskCreator,
+    #[msg("Invalid task time window")]
+    InvalidTaskWindow,
+    #[msg("Task has not started yet")]
+    TaskNotStarted,
+    #[msg("Task has expired")]
+    TaskExpired,
+    #[msg("Repeatable tasks must be claimed with submit_repeatable_proof")]
+    TaskIsRepeatable,
+    #[msg("Task is not repeatable")]
+    TaskNotRepeatable,
+    #[msg("Task already has claims")]
+    TaskAlreadyClaimed,
+    #[msg("Completion index does not match the agent's next completion")]
+    InvalidCompletionIndex,
+    #[msg("Task cooldown has not elapsed")]
+    TaskCooldownActive,
+    #[msg("Agent has reached the maximum completions for this task")]
+    MaxCompletionsReached,
+    #[msg("Invalid prerequisite set")]
+    InvalidPrerequisiteSet,
+    #[msg("Prerequisite task does not exist")]
+    PrerequisiteTaskNotFound,
+    #[msg("Prerequisite chain account missing")]
+    MissingPrerequisiteTask,
+    #[msg("Task prerequisites form a cycle")]
+    PrerequisiteCycle,
+    #[msg("Prerequisite chain is too deep")]
+    PrerequisiteChainTooDeep,
+    #[msg("Invalid verification mode")]
+    InvalidVerificationMode,
+    #[msg("Claim is not pending")]
+    ClaimNotPending,
+    #[msg("Challenge window is still open")]
+    ChallengeWindowOpen,
+    #[msg("Challenge window has closed")]
+    ChallengeWindowClosed,
+    #[msg("Invalid validator")]
+    InvalidValidator,
+    #[msg("Invalid basis points value")]
+    InvalidBasisPoints,
+    #[msg("Agent tier is too low to challenge claims")]
+    ChallengerTierTooLow,
+    #[msg("Challenge stake is below the minimum")]
+    ChallengeStakeTooLow,
+    #[msg("Insufficient Clips balance")]
+    InsufficientClips,
+    #[msg("Only approved claims can be challenged")]
+    ClaimNotChallengeable,
+    #[msg("Agents cannot challenge their own claims")]
+    SelfChallengeNotAllowed,
+    #[msg("Challenge is not open")]
+    ChallengeNotOpen,
+    #[msg("Agent tier is too low to flag claims")]
+    FlaggerTierTooLow,
+    #[msg("Agents cannot flag their own claims")]
+    SelfFlagNotAllowed,
+    #[msg("Claim payout is frozen")]
+    ClaimPayoutFrozen,
+    #[msg("Slash amount must be greater than zero")]
+    InvalidSlashAmount,
+    #[msg("Agent is frozen")]
+    AgentFrozen,
+    #[msg("Freeze expiry must be in the future")]
+    InvalidFreezeExpiry,
+    #[msg("Appeal must reference exactly one slash record, claim or rejection")]
+    InvalidAppealSubject,
+    #[msg("Claim is not revoked")]
+    ClaimNotAppealable,
+    #[msg("Appeal is not open")]
+    AppealNotOpen,
+    #[msg("Tier thresholds must not decrease from one tier to the next")]
+    InvalidTierThresholds,
+    #[msg("Agent is already at the highest tier")]
+    AgentAtMaxTier,
+    #[msg("Agent does not meet the next tier's requirements")]
+    PromotionRequirementsNotMet,
+    #[msg("Promotion cooldown is still active")]
+    PromotionCooldownActive,
+    #[msg("Agent is not due for tier decay")]
+    TierDecayNotDue,
+    #[msg("Emission policy account is required")]
+    EmissionPolicyRequired,
+    #[msg("Clips supply cap or epoch budget is exhausted")]
+    EmissionExhausted,
+    #[msg("Emission epoch duration must be greater than zero")]
+    InvalidEmissionPolicy,
+    #[msg("Invalid reward mode")]
+    InvalidRewardMode,
+    #[msg("Invalid reward curve")]
+    InvalidRewardCurve,
+    #[msg("Reward event window, multiplier or task range is invalid")]
+    InvalidRewardEvent,
+    #[msg("Reward event does not apply to this submission")]
+    RewardEventNotApplicable,
+    #[msg("Tier reward multipliers must be greater than zero")]
+    InvalidTierMultiplier,
+    #[msg("Task id belongs to a closed task and cannot be reused")]
+    TaskIdRetired,
+    #[msg("Task still has pending claims to settle")]
+    TaskHasUnsettledClaims,
 }
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::PROTOCOL_SEED, error::ErrorCode, state::ProtocolState};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.pending_authority == new_authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let protocol = &mut ctx.accounts.protocol;
+    protocol.authority = ctx.accounts.new_authority.key();
+    protocol.pending_authority = Pubkey::default();
+    Ok(())
+}
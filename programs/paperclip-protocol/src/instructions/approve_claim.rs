@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        AGENT_SEED, CLAIM_STATUS_APPROVED, CLAIM_STATUS_AWAITING_VALIDATOR, PROTOCOL_SEED,
+        TASK_SEED,
+    },
+    error::ErrorCode,
+    instructions::submit_proof::credit_clips,
+    state::{AgentAccount, ClaimRecord, ProtocolState, TaskRecord},
+};
+
+#[derive(Accounts)]
+pub struct ApproveClaim<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, claim.task_id.to_le_bytes().as_ref()],
+        bump = task.bump,
+        constraint = task.validator == validator.key() @ ErrorCode::Unauthorized
+    )]
+    pub task: Account<'info, TaskRecord>,
+    #[account(mut)]
+    pub claim: Account<'info, ClaimRecord>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, claim.agent.as_ref()],
+        bump = agent_account.bump
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+    pub validator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveClaim>) -> Result<()> {
+    let protocol = &mut ctx.accounts.protocol;
+    let claim = &mut ctx.accounts.claim;
+
+    require!(!protocol.paused, ErrorCode::ProtocolPaused);
+    require!(
+        claim.status == CLAIM_STATUS_AWAITING_VALIDATOR,
+        ErrorCode::ClaimNotPending
+    );
+    require!(!claim.payout_frozen, ErrorCode::ClaimPayoutFrozen);
+
+    credit_clips(
+        protocol,
+        &mut ctx.accounts.agent_account,
+        claim.clips_awarded,
+    )?;
+    claim.status = CLAIM_STATUS_APPROVED;
+    claim.finalizes_at = Clock::get()?.unix_timestamp;
+    ctx.accounts.task.settle_claim();
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, AGENT_SEED, CHALLENGE_RESERVED_BYTES, CHALLENGE_SEED,
+        CHALLENGE_STATUS_OPEN, CLAIM_STATUS_APPROVED, DISPUTE_CONFIG_SEED, PROTOCOL_SEED,
+    },
+    error::ErrorCode,
+    state::{AgentAccount, ChallengeRecord, ClaimRecord, DisputeConfig, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct ChallengeClaim<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        seeds = [DISPUTE_CONFIG_SEED],
+        bump = dispute_config.bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    pub claim: Account<'info, ClaimRecord>,
+    #[account(
+        init,
+        payer = challenger,
+        space = ChallengeRecord::SPACE,
+        seeds = [CHALLENGE_SEED, claim.key().as_ref()],
+        bump
+    )]
+    pub challenge: Account<'info, ChallengeRecord>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, challenger.key().as_ref()],
+        bump = challenger_agent.bump
+    )]
+    pub challenger_agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Disputes an approved claim, moving `stake` Clips from the challenger's
+/// balance into the challenge until the authority resolves it.
+pub fn handler(ctx: Context<ChallengeClaim>, stake: u64, evidence_cid: [u8; 64]) -> Result<()> {
+    let dispute_config = &ctx.accounts.dispute_config;
+    let claim = &ctx.accounts.claim;
+    let challenger_agent = &mut ctx.accounts.challenger_agent;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!ctx.accounts.protocol.paused, ErrorCode::ProtocolPaused);
+    require!(
+        claim.status == CLAIM_STATUS_APPROVED,
+        ErrorCode::ClaimNotChallengeable
+    );
+    require_keys_neq!(
+        claim.agent,
+        ctx.accounts.challenger.key(),
+        ErrorCode::SelfChallengeNotAllowed
+    );
+    require!(
+        challenger_agent.efficiency_tier >= dispute_config.min_challenger_tier,
+        ErrorCode::ChallengerTierTooLow
+    );
+    require!(
+        stake >= dispute_config.min_challenge_stake,
+        ErrorCode::ChallengeStakeTooLow
+    );
+
+    challenger_agent.clips_balance = challenger_agent
+        .clips_balance
+        .checked_sub(stake)
+        .ok_or(ErrorCode::InsufficientClips)?;
+    challenger_agent.last_active_at = now;
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.bump = ctx.bumps.challenge;
+    challenge.layout_version = ACCOUNT_LAYOUT_V1;
+    challenge.claim = claim.key();
+    challenge.claimant = claim.agent;
+    challenge.challenger = ctx.accounts.challenger.key();
+    challenge.stake = stake;
+    challenge.evidence_cid = evidence_cid;
+    challenge.status = CHALLENGE_STATUS_OPEN;
+    challenge.created_at = now;
+    challenge.resolved_at = 0;
+    challenge.reserved = [0; CHALLENGE_RESERVED_BYTES];
+
+    Ok(())
+}
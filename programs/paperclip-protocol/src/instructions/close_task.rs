@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, PREREQ_SEED, PROTOCOL_SEED, PUBLISHER_CAN_DEACTIVATE, PUBLISHER_SEED,
+        TASK_SEED, TASK_TOMBSTONE_RESERVED_BYTES, TASK_TOMBSTONE_SEED,
+    },
+    error::ErrorCode,
+    state::{ProtocolState, PublisherRole, TaskPrerequisites, TaskRecord, TaskTombstone},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct CloseTask<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        close = creator,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump,
+        has_one = creator @ ErrorCode::InvalidTaskCreator
+    )]
+    pub task: Account<'info, TaskRecord>,
+    /// Required while the task has a prerequisite set; pass it for an emptied
+    /// set too to reclaim its rent.
+    #[account(
+        mut,
+        close = creator,
+        seeds = [PREREQ_SEED, task_id.to_le_bytes().as_ref()],
+        bump = prerequisites.bump
+    )]
+    pub prerequisites: Option<Account<'info, TaskPrerequisites>>,
+    #[account(
+        init,
+        payer = authority,
+        space = TaskTombstone::SPACE,
+        seeds = [TASK_TOMBSTONE_SEED, task_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tombstone: Account<'info, TaskTombstone>,
+    /// Receives the task's rent; must match `task.creator`.
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+    /// Protocol authority or a wallet holding a `PublisherRole`; pays for the
+    /// tombstone.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [PUBLISHER_SEED, authority.key().as_ref()],
+        bump = publisher_role.bump
+    )]
+    pub publisher_role: Option<Account<'info, PublisherRole>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes an inactive task and retires its id: old claims on the id stay valid
+/// prerequisite proofs, so `create_task` must never hand it out again.
+pub fn handler(ctx: Context<CloseTask>, task_id: u32) -> Result<()> {
+    PublisherRole::authorize(
+        &ctx.accounts.protocol,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.publisher_role.as_deref(),
+        PUBLISHER_CAN_DEACTIVATE,
+        task_id,
+    )?;
+
+    let task = &ctx.accounts.task;
+    require!(!task.is_active, ErrorCode::TaskStillActive);
+    require!(
+        task.unsettled_claims == 0,
+        ErrorCode::TaskHasUnsettledClaims
+    );
+    require!(
+        !task.has_prerequisite_set || ctx.accounts.prerequisites.is_some(),
+        ErrorCode::InvalidPrerequisiteAccount
+    );
+
+    let tombstone = &mut ctx.accounts.tombstone;
+    tombstone.bump = ctx.bumps.tombstone;
+    tombstone.layout_version = ACCOUNT_LAYOUT_V1;
+    tombstone.task_id = task_id;
+    tombstone.closed_at = Clock::get()?.unix_timestamp;
+    tombstone.reserved = [0; TASK_TOMBSTONE_RESERVED_BYTES];
+
+    Ok(())
+}
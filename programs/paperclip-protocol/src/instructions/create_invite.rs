@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 
 use crate::{
     constants::{
-        ACCOUNT_LAYOUT_V1, AGENT_SEED, INVITE_RESERVED_BYTES, INVITE_SEED, PROTOCOL_SEED,
+        ACCOUNT_LAYOUT_V1, AGENT_SEED, FEATURE_INVITE_CREATION_DISABLED, INVITE_RESERVED_BYTES,
+        INVITE_SEED, PROTOCOL_SEED,
     },
     error::ErrorCode,
     state::{AgentAccount, InviteRecord, ProtocolState},
@@ -35,8 +36,20 @@ pub struct CreateInvite<'info> {
 }
 
 pub fn handler(ctx: Context<CreateInvite>) -> Result<()> {
-    let invite_record = &mut ctx.accounts.invite_record;
+    let protocol = &ctx.accounts.protocol;
+    require!(!protocol.paused, ErrorCode::ProtocolPaused);
+    require!(
+        !protocol.is_feature_disabled(FEATURE_INVITE_CREATION_DISABLED),
+        ErrorCode::InviteCreationDisabled
+    );
+
     let now = Clock::get()?.unix_timestamp;
+    require!(
+        !ctx.accounts.agent_account.is_frozen(now),
+        ErrorCode::AgentFrozen
+    );
+
+    let invite_record = &mut ctx.accounts.invite_record;
 
     invite_record.bump = ctx.bumps.invite_record;
     invite_record.layout_version = ACCOUNT_LAYOUT_V1;
@@ -2,10 +2,13 @@ use anchor_lang::prelude::*;
 
 use crate::{
     constants::{
-        ACCOUNT_LAYOUT_V1, NO_PREREQ_TASK_ID, PROTOCOL_SEED, TASK_RESERVED_BYTES, TASK_SEED,
+        ACCOUNT_LAYOUT_V1, MAX_PREREQ_CHAIN_DEPTH, NO_PREREQ_TASK_ID, PROTOCOL_SEED,
+        PUBLISHER_CAN_CREATE, PUBLISHER_SEED, REWARD_CURVE_BY_CLAIMS, REWARD_CURVE_NONE,
+        REWARD_MODE_FIXED, TASK_RESERVED_BYTES, TASK_SEED, TASK_TOMBSTONE_SEED,
+        VERIFICATION_INSTANT,
     },
     error::ErrorCode,
-    state::{ProtocolState, TaskRecord},
+    state::{ProtocolState, PublisherRole, TaskRecord},
 };
 
 #[derive(Accounts)]
@@ -14,10 +17,10 @@ pub struct CreateTask<'info> {
     #[account(
         mut,
         seeds = [PROTOCOL_SEED],
-        bump = protocol.bump,
-        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+        bump = protocol.bump
     )]
     pub protocol: Account<'info, ProtocolState>,
+    /// Protocol authority or a wallet holding a `PublisherRole`.
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
@@ -28,7 +31,19 @@ pub struct CreateTask<'info> {
         bump
     )]
     pub task: Account<'info, TaskRecord>,
+    /// CHECK: only read to confirm `close_task` has not retired this id.
+    #[account(
+        seeds = [TASK_TOMBSTONE_SEED, task_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = tombstone.data_is_empty() @ ErrorCode::TaskIdRetired
+    )]
+    pub tombstone: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [PUBLISHER_SEED, authority.key().as_ref()],
+        bump = publisher_role.bump
+    )]
+    pub publisher_role: Option<Account<'info, PublisherRole>>,
 }
 
 pub fn handler(
@@ -41,6 +56,43 @@ pub fn handler(
     min_tier: u8,
     required_task_id: u32,
 ) -> Result<()> {
+    create(
+        ctx,
+        task_id,
+        title,
+        content_cid,
+        reward_clips,
+        max_claims,
+        min_tier,
+        required_task_id,
+        0,
+        0,
+    )
+}
+
+/// Shared by `create_task` and its variants; a zero `starts_at`/`ends_at`
+/// leaves that side of the claim window open.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create(
+    ctx: Context<CreateTask>,
+    task_id: u32,
+    title: [u8; 32],
+    content_cid: [u8; 64],
+    reward_clips: u64,
+    max_claims: u16,
+    min_tier: u8,
+    required_task_id: u32,
+    starts_at: i64,
+    ends_at: i64,
+) -> Result<()> {
+    PublisherRole::authorize(
+        &ctx.accounts.protocol,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.publisher_role.as_deref(),
+        PUBLISHER_CAN_CREATE,
+        task_id,
+    )?;
+
     let task = &mut ctx.accounts.task;
     let protocol = &mut ctx.accounts.protocol;
     let now = Clock::get()?.unix_timestamp;
@@ -50,6 +102,12 @@ pub fn handler(
             required_task_id != task_id,
             ErrorCode::InvalidTaskPrerequisite
         );
+        validate_prerequisite_chain(
+            task_id,
+            required_task_id,
+            ctx.remaining_accounts,
+            ctx.program_id,
+        )?;
     }
 
     task.bump = ctx.bumps.task;
@@ -65,6 +123,24 @@ pub fn handler(
     task.created_at = now;
     task.min_tier = min_tier;
     task.required_task_id = required_task_id;
+    task.edit_count = 0;
+    task.updated_at = 0;
+    task.starts_at = starts_at;
+    task.ends_at = ends_at;
+    task.is_repeatable = false;
+    task.cooldown_seconds = 0;
+    task.max_completions = 0;
+    task.has_prerequisite_set = false;
+    task.verification_mode = VERIFICATION_INSTANT;
+    task.challenge_window_seconds = 0;
+    task.validator = Pubkey::default();
+    task.reward_mode = REWARD_MODE_FIXED;
+    task.reward_base_unit_bps = 0;
+    task.reward_curve = REWARD_CURVE_NONE;
+    task.reward_curve_basis = REWARD_CURVE_BY_CLAIMS;
+    task.reward_floor_bps = 0;
+    task.reward_curve_period = 0;
+    task.unsettled_claims = 0;
     task.reserved = [0; TASK_RESERVED_BYTES];
 
     protocol.total_tasks = protocol
@@ -74,3 +150,60 @@ pub fn handler(
 
     Ok(())
 }
+
+/// Walks the prerequisite chain starting at `required_task_id`, using one
+/// `TaskRecord` per link from `remaining_accounts`. The direct prerequisite must
+/// exist; the walk ends at a task without a prerequisite or at a link whose
+/// record no longer exists, and fails if it reaches `task_id`.
+fn validate_prerequisite_chain(
+    task_id: u32,
+    required_task_id: u32,
+    remaining_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let mut chain = remaining_accounts.iter();
+    let mut next_task_id = required_task_id;
+
+    for depth in 0..MAX_PREREQ_CHAIN_DEPTH {
+        if next_task_id == NO_PREREQ_TASK_ID {
+            return Ok(());
+        }
+        require!(next_task_id != task_id, ErrorCode::PrerequisiteCycle);
+
+        let account = chain.next().ok_or(ErrorCode::MissingPrerequisiteTask)?;
+        match load_task_record(account, next_task_id, program_id)? {
+            Some(prerequisite) => next_task_id = prerequisite.required_task_id,
+            None => {
+                require!(depth > 0, ErrorCode::PrerequisiteTaskNotFound);
+                return Ok(());
+            }
+        }
+    }
+
+    err!(ErrorCode::PrerequisiteChainTooDeep)
+}
+
+/// Reads the `TaskRecord` for `task_id` from `account`, which must sit at the
+/// task's PDA. Returns `None` if no task lives there (never created or closed).
+pub(crate) fn load_task_record(
+    account: &AccountInfo,
+    task_id: u32,
+    program_id: &Pubkey,
+) -> Result<Option<TaskRecord>> {
+    let expected_task_pda =
+        Pubkey::find_program_address(&[TASK_SEED, task_id.to_le_bytes().as_ref()], program_id).0;
+    require_keys_eq!(
+        *account.key,
+        expected_task_pda,
+        ErrorCode::InvalidPrerequisiteAccount
+    );
+
+    if *account.owner != *program_id {
+        return Ok(None);
+    }
+    let data = account
+        .try_borrow_data()
+        .map_err(|_| error!(ErrorCode::InvalidPrerequisiteAccount))?;
+    let mut slice: &[u8] = &data;
+    Ok(TaskRecord::try_deserialize(&mut slice).ok())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, instructions::create_task::CreateTask};
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateTask>,
+    task_id: u32,
+    title: [u8; 32],
+    content_cid: [u8; 64],
+    reward_clips: u64,
+    max_claims: u16,
+    min_tier: u8,
+    required_task_id: u32,
+    starts_at: i64,
+    ends_at: i64,
+) -> Result<()> {
+    require!(starts_at >= 0 && ends_at >= 0, ErrorCode::InvalidTaskWindow);
+    if starts_at != 0 && ends_at != 0 {
+        require!(starts_at < ends_at, ErrorCode::InvalidTaskWindow);
+    }
+
+    crate::instructions::create_task::create(
+        ctx,
+        task_id,
+        title,
+        content_cid,
+        reward_clips,
+        max_claims,
+        min_tier,
+        required_task_id,
+        starts_at,
+        ends_at,
+    )
+}
@@ -1,9 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{PROTOCOL_SEED, TASK_SEED},
-    error::ErrorCode,
-    state::{ProtocolState, TaskRecord},
+    constants::{PROTOCOL_SEED, PUBLISHER_CAN_DEACTIVATE, PUBLISHER_SEED, TASK_SEED},
+    state::{ProtocolState, PublisherRole, TaskRecord},
 };
 
 #[derive(Accounts)]
@@ -11,8 +10,7 @@ use crate::{
 pub struct DeactivateTask<'info> {
     #[account(
         seeds = [PROTOCOL_SEED],
-        bump = protocol.bump,
-        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+        bump = protocol.bump
     )]
     pub protocol: Account<'info, ProtocolState>,
     #[account(
@@ -21,10 +19,24 @@ pub struct DeactivateTask<'info> {
         bump = task.bump
     )]
     pub task: Account<'info, TaskRecord>,
+    /// Protocol authority or a wallet holding a `PublisherRole`.
     pub authority: Signer<'info>,
+    #[account(
+        seeds = [PUBLISHER_SEED, authority.key().as_ref()],
+        bump = publisher_role.bump
+    )]
+    pub publisher_role: Option<Account<'info, PublisherRole>>,
 }
 
-pub fn handler(ctx: Context<DeactivateTask>, _task_id: u32) -> Result<()> {
+pub fn handler(ctx: Context<DeactivateTask>, task_id: u32) -> Result<()> {
+    PublisherRole::authorize(
+        &ctx.accounts.protocol,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.publisher_role.as_deref(),
+        PUBLISHER_CAN_DEACTIVATE,
+        task_id,
+    )?;
+
     let task = &mut ctx.accounts.task;
     task.is_active = false;
     Ok(())
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AGENT_SEED, PROTOCOL_SEED, TIER_CONFIG_SEED, TIER_INTERN},
+    error::ErrorCode,
+    state::{AgentAccount, ProtocolState, TierConfig},
+};
+
+/// Permissionless: anyone can crank an inactive agent down one tier.
+#[derive(Accounts)]
+pub struct DecayTier<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        seeds = [TIER_CONFIG_SEED],
+        bump = tier_config.bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_account.wallet.as_ref()],
+        bump = agent_account.bump
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+}
+
+/// Drops the agent one tier once it has been inactive for its tier's decay
+/// window. Promotions and earlier decays restart the window, so a dormant agent
+/// loses one tier per window rather than all at once.
+pub fn handler(ctx: Context<DecayTier>) -> Result<()> {
+    let agent_account = &mut ctx.accounts.agent_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!ctx.accounts.protocol.paused, ErrorCode::ProtocolPaused);
+    require!(
+        agent_account.efficiency_tier > TIER_INTERN,
+        ErrorCode::TierDecayNotDue
+    );
+
+    let window =
+        ctx.accounts.tier_config.decay_after_seconds[usize::from(agent_account.efficiency_tier)];
+    require!(window != 0, ErrorCode::TierDecayNotDue);
+
+    let last_activity = agent_account
+        .last_active_at
+        .max(agent_account.last_promoted_at)
+        .max(agent_account.last_decayed_at);
+    let decays_at = last_activity
+        .checked_add(i64::from(window))
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(now >= decays_at, ErrorCode::TierDecayNotDue);
+
+    agent_account.efficiency_tier -= 1;
+    agent_account.last_decayed_at = now;
+
+    Ok(())
+}
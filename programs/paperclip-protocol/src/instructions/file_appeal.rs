@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, APPEAL_RESERVED_BYTES, APPEAL_SEED, APPEAL_STATUS_OPEN,
+        APPEAL_SUBJECT_CLAIM, APPEAL_SUBJECT_REJECTION, APPEAL_SUBJECT_SLASH, CLAIM_STATUS_REVOKED,
+        PROTOCOL_SEED,
+    },
+    error::ErrorCode,
+    state::{AppealRecord, ClaimRecord, ProtocolState, RejectionRecord, SlashRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(appeal_cid: [u8; 64])]
+pub struct FileAppeal<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    /// Pass exactly one of `slash_record`, `claim` or `rejection`.
+    pub slash_record: Option<Account<'info, SlashRecord>>,
+    pub claim: Option<Account<'info, ClaimRecord>>,
+    pub rejection: Option<Account<'info, RejectionRecord>>,
+    /// One appeal per subject; the seed is whichever subject was passed.
+    #[account(
+        init,
+        payer = appellant,
+        space = AppealRecord::SPACE,
+        seeds = [
+            APPEAL_SEED,
+            appeal_subject(&slash_record, &claim, &rejection).as_ref()
+        ],
+        bump
+    )]
+    pub appeal: Account<'info, AppealRecord>,
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+fn appeal_subject(
+    slash_record: &Option<Account<SlashRecord>>,
+    claim: &Option<Account<ClaimRecord>>,
+    rejection: &Option<Account<RejectionRecord>>,
+) -> Pubkey {
+    match (slash_record, claim, rejection) {
+        (Some(slash_record), None, None) => slash_record.key(),
+        (None, Some(claim), None) => claim.key(),
+        (None, None, Some(rejection)) => rejection.key(),
+        _ => Pubkey::default(),
+    }
+}
+
+/// Contests a slash, a revoked claim or a claim rejection. Only the affected
+/// agent can appeal, and the authority settles it with `resolve_appeal`.
+pub fn handler(ctx: Context<FileAppeal>, appeal_cid: [u8; 64]) -> Result<()> {
+    require!(!ctx.accounts.protocol.paused, ErrorCode::ProtocolPaused);
+
+    let appellant = ctx.accounts.appellant.key();
+    let (subject, subject_kind) = match (
+        &ctx.accounts.slash_record,
+        &ctx.accounts.claim,
+        &ctx.accounts.rejection,
+    ) {
+        (Some(slash_record), None, None) => {
+            require_keys_eq!(slash_record.agent, appellant, ErrorCode::Unauthorized);
+            (slash_record.key(), APPEAL_SUBJECT_SLASH)
+        }
+        (None, Some(claim), None) => {
+            require_keys_eq!(claim.agent, appellant, ErrorCode::Unauthorized);
+            require!(
+                claim.status == CLAIM_STATUS_REVOKED,
+                ErrorCode::ClaimNotAppealable
+            );
+            (claim.key(), APPEAL_SUBJECT_CLAIM)
+        }
+        (None, None, Some(rejection)) => {
+            require_keys_eq!(rejection.agent, appellant, ErrorCode::Unauthorized);
+            (rejection.key(), APPEAL_SUBJECT_REJECTION)
+        }
+        _ => return err!(ErrorCode::InvalidAppealSubject),
+    };
+
+    let appeal = &mut ctx.accounts.appeal;
+    appeal.bump = ctx.bumps.appeal;
+    appeal.layout_version = ACCOUNT_LAYOUT_V1;
+    appeal.appellant = appellant;
+    appeal.subject = subject;
+    appeal.subject_kind = subject_kind;
+    appeal.appeal_cid = appeal_cid;
+    appeal.status = APPEAL_STATUS_OPEN;
+    appeal.clips_restored = 0;
+    appeal.created_at = Clock::get()?.unix_timestamp;
+    appeal.resolved_at = 0;
+    appeal.reserved = [0; APPEAL_RESERVED_BYTES];
+
+    Ok(())
+}
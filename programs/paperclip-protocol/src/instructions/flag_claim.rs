@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, AGENT_SEED, CLAIM_STATUS_AWAITING_VALIDATOR, CLAIM_STATUS_PENDING,
+        DISPUTE_CONFIG_SEED, FLAG_RESERVED_BYTES, FLAG_SEED, MIN_FLAGGER_TIER, PROTOCOL_SEED,
+    },
+    error::ErrorCode,
+    state::{AgentAccount, ClaimRecord, DisputeConfig, FlagRecord, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct FlagClaim<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    /// Supplies the auto-freeze threshold when disputes are configured.
+    #[account(
+        seeds = [DISPUTE_CONFIG_SEED],
+        bump = dispute_config.bump
+    )]
+    pub dispute_config: Option<Account<'info, DisputeConfig>>,
+    #[account(mut)]
+    pub claim: Account<'info, ClaimRecord>,
+    #[account(
+        init,
+        payer = flagger,
+        space = FlagRecord::SPACE,
+        seeds = [FLAG_SEED, claim.key().as_ref(), flagger.key().as_ref()],
+        bump
+    )]
+    pub flag: Account<'info, FlagRecord>,
+    #[account(
+        seeds = [AGENT_SEED, flagger.key().as_ref()],
+        bump = flagger_agent.bump
+    )]
+    pub flagger_agent: Account<'info, AgentAccount>,
+    #[account(mut)]
+    pub flagger: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Any claim can be flagged, so the most-flagged ones can be reviewed; only
+/// payouts still under review (pending or awaiting a validator) auto-freeze.
+pub fn handler(ctx: Context<FlagClaim>, reason_code: u8, evidence_cid: [u8; 64]) -> Result<()> {
+    let claim = &mut ctx.accounts.claim;
+    let flagger = ctx.accounts.flagger.key();
+
+    require!(!ctx.accounts.protocol.paused, ErrorCode::ProtocolPaused);
+    require!(
+        ctx.accounts.flagger_agent.efficiency_tier >= MIN_FLAGGER_TIER,
+        ErrorCode::FlaggerTierTooLow
+    );
+    require_keys_neq!(claim.agent, flagger, ErrorCode::SelfFlagNotAllowed);
+
+    claim.flag_count = claim
+        .flag_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if let Some(dispute_config) = &ctx.accounts.dispute_config {
+        let under_review =
+            claim.status == CLAIM_STATUS_PENDING || claim.status == CLAIM_STATUS_AWAITING_VALIDATOR;
+        if under_review
+            && dispute_config.flag_freeze_threshold != 0
+            && claim.flag_count >= dispute_config.flag_freeze_threshold
+        {
+            claim.payout_frozen = true;
+        }
+    }
+
+    let flag = &mut ctx.accounts.flag;
+    flag.bump = ctx.bumps.flag;
+    flag.layout_version = ACCOUNT_LAYOUT_V1;
+    flag.claim = claim.key();
+    flag.flagger = flagger;
+    flag.reason_code = reason_code;
+    flag.evidence_cid = evidence_cid;
+    flag.created_at = Clock::get()?.unix_timestamp;
+    flag.reserved = [0; FLAG_RESERVED_BYTES];
+
+    Ok(())
+}
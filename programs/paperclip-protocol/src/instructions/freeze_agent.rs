@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AGENT_SEED, PROTOCOL_SEED},
+    error::ErrorCode,
+    state::{AgentAccount, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct FreezeAgent<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_account.wallet.as_ref()],
+        bump = agent_account.bump
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+    pub authority: Signer<'info>,
+}
+
+/// Freezes the agent until `frozen_until`, or indefinitely when `None`. The
+/// freeze lifts on its own once the timestamp passes.
+pub fn handler(ctx: Context<FreezeAgent>, frozen_until: Option<i64>) -> Result<()> {
+    let frozen_until = frozen_until.unwrap_or(i64::MAX);
+    require!(
+        frozen_until > Clock::get()?.unix_timestamp,
+        ErrorCode::InvalidFreezeExpiry
+    );
+
+    ctx.accounts.agent_account.frozen_until = frozen_until;
+    Ok(())
+}
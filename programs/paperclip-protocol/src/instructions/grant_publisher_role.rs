@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, PROTOCOL_SEED, PUBLISHER_PERMISSIONS_MASK, PUBLISHER_RESERVED_BYTES,
+        PUBLISHER_SEED,
+    },
+    error::ErrorCode,
+    state::{ProtocolState, PublisherRole},
+};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct GrantPublisherRole<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        init,
+        payer = authority,
+        space = PublisherRole::SPACE,
+        seeds = [PUBLISHER_SEED, wallet.as_ref()],
+        bump
+    )]
+    pub publisher_role: Account<'info, PublisherRole>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<GrantPublisherRole>,
+    wallet: Pubkey,
+    permissions: u8,
+    min_task_id: u32,
+    max_task_id: u32,
+) -> Result<()> {
+    require!(
+        permissions != 0 && permissions & !PUBLISHER_PERMISSIONS_MASK == 0,
+        ErrorCode::InvalidPublisherPermissions
+    );
+    require!(
+        min_task_id <= max_task_id,
+        ErrorCode::InvalidPublisherTaskRange
+    );
+
+    let publisher_role = &mut ctx.accounts.publisher_role;
+    publisher_role.bump = ctx.bumps.publisher_role;
+    publisher_role.layout_version = ACCOUNT_LAYOUT_V1;
+    publisher_role.wallet = wallet;
+    publisher_role.permissions = permissions;
+    publisher_role.min_task_id = min_task_id;
+    publisher_role.max_task_id = max_task_id;
+    publisher_role.granted_by = ctx.accounts.authority.key();
+    publisher_role.granted_at = Clock::get()?.unix_timestamp;
+    publisher_role.reserved = [0; PUBLISHER_RESERVED_BYTES];
+
+    Ok(())
+}
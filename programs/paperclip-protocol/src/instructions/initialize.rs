@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{ACCOUNT_LAYOUT_V1, PROTOCOL_RESERVED_BYTES, PROTOCOL_SEED},
-    state::ProtocolState,
+    constants::{
+        ACCOUNT_LAYOUT_V1, DEFAULT_INVITEE_REWARD_BPS, DEFAULT_INVITER_REWARD_BPS,
+        DEFAULT_REGISTRATION_AIRDROP_BPS, DEFAULT_TIER_REWARD_MULTIPLIER_BPS,
+        ECONOMY_CONFIG_RESERVED_BYTES, ECONOMY_CONFIG_SEED, PROTOCOL_RESERVED_BYTES, PROTOCOL_SEED,
+        TIER_COUNT,
+    },
+    state::{EconomyConfig, ProtocolState},
 };
 
 #[derive(Accounts)]
@@ -15,6 +20,14 @@ pub struct Initialize<'info> {
         bump
     )]
     pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        init,
+        payer = authority,
+        space = EconomyConfig::SPACE,
+        seeds = [ECONOMY_CONFIG_SEED],
+        bump
+    )]
+    pub economy_config: Account<'info, EconomyConfig>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -30,6 +43,20 @@ pub fn handler(ctx: Context<Initialize>, base_reward_unit: u64) -> Result<()> {
     protocol.total_tasks = 0;
     protocol.total_clips_distributed = 0;
     protocol.paused = false;
+    protocol.feature_flags = 0;
+    protocol.pending_authority = Pubkey::default();
+    protocol.clips_slashed = 0;
+    protocol.clips_burned = 0;
+    protocol.emission_policy_active = false;
     protocol.reserved = [0; PROTOCOL_RESERVED_BYTES];
+
+    let economy_config = &mut ctx.accounts.economy_config;
+    economy_config.bump = ctx.bumps.economy_config;
+    economy_config.layout_version = ACCOUNT_LAYOUT_V1;
+    economy_config.registration_airdrop_bps = DEFAULT_REGISTRATION_AIRDROP_BPS;
+    economy_config.invitee_reward_bps = DEFAULT_INVITEE_REWARD_BPS;
+    economy_config.inviter_reward_bps = DEFAULT_INVITER_REWARD_BPS;
+    economy_config.tier_reward_multiplier_bps = [DEFAULT_TIER_REWARD_MULTIPLIER_BPS; TIER_COUNT];
+    economy_config.reserved = [0; ECONOMY_CONFIG_RESERVED_BYTES];
     Ok(())
 }
@@ -1,15 +1,90 @@
+pub mod accept_authority;
+pub mod approve_claim;
 pub mod create_task;
+pub mod create_timed_task;
+pub mod challenge_claim;
+pub mod close_task;
 pub mod create_invite;
 pub mod deactivate_task;
+pub mod decay_tier;
+pub mod file_appeal;
+pub mod finalize_claim;
+pub mod flag_claim;
+pub mod freeze_agent;
+pub mod grant_publisher_role;
 pub mod initialize;
+pub mod pause_protocol;
+pub mod promote_agent;
+pub mod propose_authority;
+pub mod reactivate_task;
 pub mod register_agent;
 pub mod register_agent_with_invite;
+pub mod reject_claim;
+pub mod resolve_appeal;
+pub mod resolve_challenge;
+pub mod revoke_publisher_role;
+pub mod set_dispute_config;
+pub mod set_emission_policy;
+pub mod set_feature_flags;
+pub mod set_reward_event;
+pub mod set_task_prerequisites;
+pub mod set_task_repeatable;
+pub mod set_task_reward_curve;
+pub mod set_task_reward_mode;
+pub mod set_task_validator;
+pub mod set_task_verification;
+pub mod set_tier_config;
+pub mod set_tier_multipliers;
+pub mod slash_agent;
 pub mod submit_proof;
+pub mod submit_repeatable_proof;
+pub mod unfreeze_agent;
+pub mod unfreeze_claim;
+pub mod unpause_protocol;
+pub mod update_economy;
+pub mod update_task;
 
+pub use accept_authority::*;
+pub use approve_claim::*;
 pub use create_task::*;
+pub use challenge_claim::*;
+pub use close_task::*;
 pub use create_invite::*;
 pub use deactivate_task::*;
+pub use decay_tier::*;
+pub use file_appeal::*;
+pub use finalize_claim::*;
+pub use flag_claim::*;
+pub use freeze_agent::*;
+pub use grant_publisher_role::*;
 pub use initialize::*;
+pub use pause_protocol::*;
+pub use promote_agent::*;
+pub use propose_authority::*;
+pub use reactivate_task::*;
 pub use register_agent::*;
 pub use register_agent_with_invite::*;
+pub use reject_claim::*;
+pub use resolve_appeal::*;
+pub use resolve_challenge::*;
+pub use revoke_publisher_role::*;
+pub use set_dispute_config::*;
+pub use set_emission_policy::*;
+pub use set_feature_flags::*;
+pub use set_reward_event::*;
+pub use set_task_prerequisites::*;
+pub use set_task_repeatable::*;
+pub use set_task_reward_curve::*;
+pub use set_task_reward_mode::*;
+pub use set_task_validator::*;
+pub use set_task_verification::*;
+pub use set_tier_config::*;
+pub use set_tier_multipliers::*;
+pub use slash_agent::*;
 pub use submit_proof::*;
+pub use submit_repeatable_proof::*;
+pub use unfreeze_agent::*;
+pub use unfreeze_claim::*;
+pub use unpause_protocol::*;
+pub use update_economy::*;
+pub use update_task::*;
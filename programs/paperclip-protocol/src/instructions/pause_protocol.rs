@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::PROTOCOL_SEED, error::ErrorCode, state::ProtocolState};
+
+#[derive(Accounts)]
+pub struct PauseProtocol<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<PauseProtocol>) -> Result<()> {
+    ctx.accounts.protocol.paused = true;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AGENT_SEED, PROTOCOL_SEED, TIER_ARCHITECT, TIER_CONFIG_SEED},
+    error::ErrorCode,
+    state::{AgentAccount, ProtocolState, TierConfig},
+};
+
+#[derive(Accounts)]
+pub struct PromoteAgent<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        seeds = [TIER_CONFIG_SEED],
+        bump = tier_config.bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent.key().as_ref()],
+        bump = agent_account.bump,
+        constraint = agent_account.wallet == agent.key() @ ErrorCode::Unauthorized
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+    pub agent: Signer<'info>,
+}
+
+/// Moves the agent up one tier once it holds the next tier's minimum Clips and
+/// completed tasks and the promotion cooldown has passed.
+pub fn handler(ctx: Context<PromoteAgent>) -> Result<()> {
+    let tier_config = &ctx.accounts.tier_config;
+    let agent_account = &mut ctx.accounts.agent_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!ctx.accounts.protocol.paused, ErrorCode::ProtocolPaused);
+    require!(!agent_account.is_frozen(now), ErrorCode::AgentFrozen);
+    require!(
+        agent_account.efficiency_tier < TIER_ARCHITECT,
+        ErrorCode::AgentAtMaxTier
+    );
+
+    let promotable_at = agent_account
+        .last_promoted_at
+        .checked_add(i64::from(tier_config.promotion_cooldown_seconds))
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(now >= promotable_at, ErrorCode::PromotionCooldownActive);
+
+    let next_tier = agent_account.efficiency_tier + 1;
+    require!(
+        agent_account.clips_balance >= tier_config.min_clips_balance[usize::from(next_tier)]
+            && agent_account.tasks_completed
+                >= tier_config.min_tasks_completed[usize::from(next_tier)],
+        ErrorCode::PromotionRequirementsNotMet
+    );
+
+    agent_account.efficiency_tier = next_tier;
+    agent_account.last_promoted_at = now;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::PROTOCOL_SEED, error::ErrorCode, state::ProtocolState};
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    pub authority: Signer<'info>,
+}
+
+/// Stages `new_authority` as the pending authority. Passing `Pubkey::default()`
+/// cancels an outstanding proposal.
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    ctx.accounts.protocol.pending_authority = new_authority;
+    Ok(())
+}
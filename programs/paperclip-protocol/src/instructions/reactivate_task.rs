@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PROTOCOL_SEED, PUBLISHER_CAN_DEACTIVATE, PUBLISHER_SEED, TASK_SEED},
+    state::{ProtocolState, PublisherRole, TaskRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct ReactivateTask<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    /// Protocol authority or a wallet holding a `PublisherRole`.
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [PUBLISHER_SEED, authority.key().as_ref()],
+        bump = publisher_role.bump
+    )]
+    pub publisher_role: Option<Account<'info, PublisherRole>>,
+}
+
+pub fn handler(ctx: Context<ReactivateTask>, task_id: u32) -> Result<()> {
+    PublisherRole::authorize(
+        &ctx.accounts.protocol,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.publisher_role.as_deref(),
+        PUBLISHER_CAN_DEACTIVATE,
+        task_id,
+    )?;
+
+    let task = &mut ctx.accounts.task;
+    task.is_active = true;
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{ACCOUNT_LAYOUT_V1, AGENT_RESERVED_BYTES, AGENT_SEED, PROTOCOL_SEED},
+    constants::{
+        ACCOUNT_LAYOUT_V1, AGENT_RESERVED_BYTES, AGENT_SEED, ECONOMY_CONFIG_SEED,
+        EMISSION_POLICY_SEED, PROTOCOL_SEED,
+    },
     error::ErrorCode,
-    state::{AgentAccount, ProtocolState},
+    state::{AgentAccount, EconomyConfig, EmissionPolicy, ProtocolState},
 };
 
 #[derive(Accounts)]
@@ -14,6 +17,17 @@ pub struct RegisterAgent<'info> {
         bump = protocol.bump
     )]
     pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        seeds = [ECONOMY_CONFIG_SEED],
+        bump = economy_config.bump
+    )]
+    pub economy_config: Account<'info, EconomyConfig>,
+    #[account(
+        mut,
+        seeds = [EMISSION_POLICY_SEED],
+        bump = emission_policy.bump
+    )]
+    pub emission_policy: Option<Account<'info, EmissionPolicy>>,
     #[account(
         init,
         payer = agent,
@@ -32,10 +46,25 @@ pub fn handler(ctx: Context<RegisterAgent>) -> Result<()> {
     let agent_account = &mut ctx.accounts.agent_account;
     let now = Clock::get()?.unix_timestamp;
 
+    require!(!protocol.paused, ErrorCode::ProtocolPaused);
+
+    let mut emission_policy =
+        protocol.emission_policy(ctx.accounts.emission_policy.as_deref_mut())?;
+    if let Some(policy) = emission_policy.as_deref_mut() {
+        policy.advance(protocol, now)?;
+    }
+    let mut airdrop = ctx
+        .accounts
+        .economy_config
+        .registration_airdrop(protocol.base_reward_unit)?;
+    if let Some(policy) = emission_policy {
+        airdrop = policy.allocate(airdrop)?;
+    }
+
     agent_account.bump = ctx.bumps.agent_account;
     agent_account.layout_version = ACCOUNT_LAYOUT_V1;
     agent_account.wallet = ctx.accounts.agent.key();
-    agent_account.clips_balance = protocol.base_reward_unit;
+    agent_account.clips_balance = airdrop;
     agent_account.efficiency_tier = 0;
     agent_account.tasks_completed = 0;
     agent_account.registered_at = now;
@@ -43,6 +72,12 @@ pub fn handler(ctx: Context<RegisterAgent>) -> Result<()> {
     agent_account.invites_sent = 0;
     agent_account.invites_redeemed = 0;
     agent_account.invited_by = Pubkey::default();
+    agent_account.clips_slashed = 0;
+    agent_account.slash_count = 0;
+    agent_account.frozen_until = 0;
+    agent_account.last_promoted_at = 0;
+    agent_account.last_decayed_at = 0;
+    agent_account.rejection_count = 0;
     agent_account.reserved = [0; AGENT_RESERVED_BYTES];
 
     protocol.total_agents = protocol
@@ -51,7 +86,7 @@ pub fn handler(ctx: Context<RegisterAgent>) -> Result<()> {
         .ok_or(ErrorCode::MathOverflow)?;
     protocol.total_clips_distributed = protocol
         .total_clips_distributed
-        .checked_add(protocol.base_reward_unit)
+        .checked_add(airdrop)
         .ok_or(ErrorCode::MathOverflow)?;
 
     Ok(())
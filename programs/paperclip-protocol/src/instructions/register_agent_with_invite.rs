@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{ACCOUNT_LAYOUT_V1, AGENT_RESERVED_BYTES, AGENT_SEED, INVITE_SEED, PROTOCOL_SEED},
+    constants::{
+        ACCOUNT_LAYOUT_V1, AGENT_RESERVED_BYTES, AGENT_SEED, ECONOMY_CONFIG_SEED,
+        EMISSION_POLICY_SEED, FEATURE_INVITE_REGISTRATION_DISABLED, INVITE_SEED, PROTOCOL_SEED,
+    },
     error::ErrorCode,
-    state::{AgentAccount, InviteRecord, ProtocolState},
+    state::{AgentAccount, EconomyConfig, EmissionPolicy, InviteRecord, ProtocolState},
 };
 
 #[derive(Accounts)]
@@ -14,6 +17,17 @@ pub struct RegisterAgentWithInvite<'info> {
         bump = protocol.bump
     )]
     pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        seeds = [ECONOMY_CONFIG_SEED],
+        bump = economy_config.bump
+    )]
+    pub economy_config: Account<'info, EconomyConfig>,
+    #[account(
+        mut,
+        seeds = [EMISSION_POLICY_SEED],
+        bump = emission_policy.bump
+    )]
+    pub emission_policy: Option<Account<'info, EmissionPolicy>>,
     #[account(
         init,
         payer = agent,
@@ -46,10 +60,17 @@ pub fn handler(ctx: Context<RegisterAgentWithInvite>, invite_code: [u8; 32]) ->
     let invite_record = &mut ctx.accounts.invite_record;
     let now = Clock::get()?.unix_timestamp;
 
+    require!(!protocol.paused, ErrorCode::ProtocolPaused);
+    require!(
+        !protocol.is_feature_disabled(FEATURE_INVITE_REGISTRATION_DISABLED),
+        ErrorCode::InviteRegistrationDisabled
+    );
+
     require!(
         inviter_agent.wallet != ctx.accounts.agent.key(),
         ErrorCode::SelfReferralNotAllowed
     );
+    require!(!inviter_agent.is_frozen(now), ErrorCode::AgentFrozen);
     require!(invite_record.is_active, ErrorCode::InviteInactive);
     require_keys_eq!(
         invite_record.inviter_wallet,
@@ -65,13 +86,30 @@ pub fn handler(ctx: Context<RegisterAgentWithInvite>, invite_code: [u8; 32]) ->
         ErrorCode::InvalidInviteCode
     );
 
-    let invitee_reward = protocol
-        .base_reward_unit
-        .checked_mul(3)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(2)
+    let mut emission_policy =
+        protocol.emission_policy(ctx.accounts.emission_policy.as_deref_mut())?;
+    if let Some(policy) = emission_policy.as_deref_mut() {
+        policy.advance(protocol, now)?;
+    }
+
+    let economy_config = &ctx.accounts.economy_config;
+    let mut invitee_reward = economy_config.invitee_reward(protocol.base_reward_unit)?;
+    if let Some(policy) = emission_policy.as_deref_mut() {
+        invitee_reward = policy.allocate(invitee_reward)?;
+    }
+    protocol.total_clips_distributed = protocol
+        .total_clips_distributed
+        .checked_add(invitee_reward)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let mut inviter_bonus = economy_config.inviter_reward(protocol.base_reward_unit)?;
+    if let Some(policy) = emission_policy {
+        inviter_bonus = policy.allocate(inviter_bonus)?;
+    }
+    protocol.total_clips_distributed = protocol
+        .total_clips_distributed
+        .checked_add(inviter_bonus)
         .ok_or(ErrorCode::MathOverflow)?;
-    let inviter_bonus = protocol.base_reward_unit / 2;
 
     agent_account.bump = ctx.bumps.agent_account;
     agent_account.layout_version = ACCOUNT_LAYOUT_V1;
@@ -84,6 +122,12 @@ pub fn handler(ctx: Context<RegisterAgentWithInvite>, invite_code: [u8; 32]) ->
     agent_account.invites_sent = 0;
     agent_account.invites_redeemed = 1;
     agent_account.invited_by = inviter_agent.wallet;
+    agent_account.clips_slashed = 0;
+    agent_account.slash_count = 0;
+    agent_account.frozen_until = 0;
+    agent_account.last_promoted_at = 0;
+    agent_account.last_decayed_at = 0;
+    agent_account.rejection_count = 0;
     agent_account.reserved = [0; AGENT_RESERVED_BYTES];
 
     inviter_agent.clips_balance = inviter_agent
@@ -105,12 +149,6 @@ pub fn handler(ctx: Context<RegisterAgentWithInvite>, invite_code: [u8; 32]) ->
         .total_agents
         .checked_add(1)
         .ok_or(ErrorCode::MathOverflow)?;
-    protocol.total_clips_distributed = protocol
-        .total_clips_distributed
-        .checked_add(invitee_reward)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_add(inviter_bonus)
-        .ok_or(ErrorCode::MathOverflow)?;
 
     Ok(())
 }
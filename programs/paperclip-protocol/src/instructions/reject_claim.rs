@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, AGENT_SEED, CLAIM_STATUS_AWAITING_VALIDATOR, CLAIM_STATUS_PENDING,
+        EMISSION_POLICY_SEED, PROTOCOL_SEED, REJECTION_RESERVED_BYTES, REJECTION_SEED, TASK_SEED,
+    },
+    error::ErrorCode,
+    state::{
+        AgentAccount, ClaimRecord, EmissionPolicy, ProtocolState, RejectionRecord, TaskRecord,
+    },
+};
+
+#[derive(Accounts)]
+pub struct RejectClaim<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    /// Required once the emission policy is active; the claim's booked reward
+    /// is released back to it.
+    #[account(
+        mut,
+        seeds = [EMISSION_POLICY_SEED],
+        bump = emission_policy.bump
+    )]
+    pub emission_policy: Option<Account<'info, EmissionPolicy>>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, claim.task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    #[account(
+        mut,
+        close = agent,
+        has_one = agent @ ErrorCode::Unauthorized
+    )]
+    pub claim: Account<'info, ClaimRecord>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent.key().as_ref()],
+        bump = agent_account.bump
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+    #[account(
+        init,
+        payer = reviewer,
+        space = RejectionRecord::SPACE,
+        seeds = [
+            REJECTION_SEED,
+            agent.key().as_ref(),
+            &agent_account.rejection_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub rejection: Account<'info, RejectionRecord>,
+    /// Claimant; receives the claim's rent back.
+    #[account(mut)]
+    pub agent: SystemAccount<'info>,
+    /// Protocol authority, or the task's validator for claims awaiting one;
+    /// pays for the rejection record.
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes a pending claim without paying out, frees its slot on the task and
+/// returns its booked reward to the emission policy.
+/// A `RejectionRecord` keeps the claim's details so the agent can appeal.
+/// Optimistic claims can only be rejected by the authority inside their
+/// challenge window, or later while frozen by flags; validator claims by the
+/// validator or the authority.
+pub fn handler(ctx: Context<RejectClaim>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let claim = &ctx.accounts.claim;
+    let reviewer = ctx.accounts.reviewer.key();
+    let is_authority = reviewer == ctx.accounts.protocol.authority;
+
+    match claim.status {
+        CLAIM_STATUS_PENDING => {
+            require!(is_authority, ErrorCode::Unauthorized);
+            require!(
+                now < claim.finalizes_at || claim.payout_frozen,
+                ErrorCode::ChallengeWindowClosed
+            );
+        }
+        CLAIM_STATUS_AWAITING_VALIDATOR => {
+            require!(
+                is_authority || reviewer == ctx.accounts.task.validator,
+                ErrorCode::Unauthorized
+            );
+        }
+        _ => return err!(ErrorCode::ClaimNotPending),
+    }
+
+    if let Some(policy) = ctx
+        .accounts
+        .protocol
+        .emission_policy(ctx.accounts.emission_policy.as_deref_mut())?
+    {
+        policy.release(claim.clips_awarded, claim.completed_at);
+    }
+
+    let agent_account = &mut ctx.accounts.agent_account;
+    let rejection = &mut ctx.accounts.rejection;
+    rejection.bump = ctx.bumps.rejection;
+    rejection.layout_version = ACCOUNT_LAYOUT_V1;
+    rejection.agent = claim.agent;
+    rejection.index = agent_account.rejection_count;
+    rejection.task_id = claim.task_id;
+    rejection.is_repeatable = ctx.accounts.task.is_repeatable;
+    rejection.completion_index = claim.completion_index;
+    rejection.proof_cid = claim.proof_cid;
+    rejection.clips_awarded = claim.clips_awarded;
+    rejection.reward_boost_bps = claim.reward_boost_bps;
+    rejection.submitted_at = claim.completed_at;
+    rejection.rejected_by = reviewer;
+    rejection.created_at = now;
+    rejection.reserved = [0; REJECTION_RESERVED_BYTES];
+
+    agent_account.rejection_count = agent_account
+        .rejection_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let task = &mut ctx.accounts.task;
+    task.current_claims = task
+        .current_claims
+        .checked_sub(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    task.settle_claim();
+
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, AGENT_SEED, APPEAL_SEED, APPEAL_STATUS_DENIED, APPEAL_STATUS_OPEN,
+        APPEAL_STATUS_UPHELD, APPEAL_SUBJECT_CLAIM, APPEAL_SUBJECT_REJECTION, APPEAL_SUBJECT_SLASH,
+        CLAIM_RESERVED_BYTES, CLAIM_SEED, CLAIM_STATUS_APPROVED, EMISSION_POLICY_SEED,
+        PROTOCOL_SEED,
+    },
+    error::ErrorCode,
+    instructions::submit_proof::credit_clips,
+    state::{
+        AgentAccount, AppealRecord, ClaimRecord, EmissionPolicy, ProtocolState, RejectionRecord,
+        SlashRecord, TaskRecord,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ResolveAppeal<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    /// Required once the emission policy is active; restored claim rewards are
+    /// booked against it.
+    #[account(
+        mut,
+        seeds = [EMISSION_POLICY_SEED],
+        bump = emission_policy.bump
+    )]
+    pub emission_policy: Option<Account<'info, EmissionPolicy>>,
+    #[account(
+        mut,
+        seeds = [APPEAL_SEED, appeal.subject.as_ref()],
+        bump = appeal.bump
+    )]
+    pub appeal: Account<'info, AppealRecord>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, appeal.appellant.as_ref()],
+        bump = agent_account.bump
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+    /// The appealed slash, for slash appeals.
+    #[account(address = appeal.subject @ ErrorCode::InvalidAppealSubject)]
+    pub slash_record: Option<Account<'info, SlashRecord>>,
+    /// The appealed claim, for revoked-claim appeals.
+    #[account(mut, address = appeal.subject @ ErrorCode::InvalidAppealSubject)]
+    pub claim: Option<Account<'info, ClaimRecord>>,
+    /// The appealed rejection and its task, for rejection appeals.
+    #[account(address = appeal.subject @ ErrorCode::InvalidAppealSubject)]
+    pub rejection: Option<Account<'info, RejectionRecord>>,
+    #[account(mut)]
+    pub task: Option<Account<'info, TaskRecord>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// Recreates the rejected claim as approved when a rejection appeal is
+    /// upheld. Fails if the agent has since resubmitted at the same address.
+    #[account(
+        init,
+        payer = authority,
+        space = ClaimRecord::SPACE,
+        seeds = [
+            CLAIM_SEED,
+            rejected_task_id(&rejection).as_ref(),
+            appeal.appellant.as_ref(),
+            rejected_completion_seed(&rejection).as_ref()
+        ],
+        bump
+    )]
+    pub restored_claim: Option<Account<'info, ClaimRecord>>,
+    pub system_program: Program<'info, System>,
+}
+
+fn rejected_task_id(rejection: &Option<Account<RejectionRecord>>) -> [u8; 4] {
+    rejection
+        .as_ref()
+        .map_or(0, |rejection| rejection.task_id)
+        .to_le_bytes()
+}
+
+/// Repeatable claims are also seeded by their completion index.
+fn rejected_completion_seed(rejection: &Option<Account<RejectionRecord>>) -> Vec<u8> {
+    match rejection {
+        Some(rejection) if rejection.is_repeatable => {
+            rejection.completion_index.to_le_bytes().to_vec()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Pays restored claim rewards as fresh emission, so they count against the
+/// supply cap and epoch budget like any other payout.
+fn restore_clips(
+    protocol: &mut ProtocolState,
+    agent_account: &mut AgentAccount,
+    emission_policy: Option<&mut EmissionPolicy>,
+    amount: u64,
+    now: i64,
+) -> Result<u64> {
+    let clips = match emission_policy {
+        Some(policy) => {
+            policy.advance(protocol, now)?;
+            policy.allocate(amount)?
+        }
+        None => amount,
+    };
+    credit_clips(protocol, agent_account, clips)?;
+    Ok(clips)
+}
+
+/// Upholding a slash appeal returns the slashed Clips and reverses the
+/// `clips_slashed` counters. Upholding a revoked-claim appeal approves the
+/// claim and repays what the challenge clawed back; upholding a rejection
+/// appeal recreates the claim, approved, if the task still has a free slot.
+pub fn handler(ctx: Context<ResolveAppeal>, uphold: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let emission_policy = ctx
+        .accounts
+        .protocol
+        .emission_policy(ctx.accounts.emission_policy.as_deref_mut())?;
+    let protocol = &mut ctx.accounts.protocol;
+    let appeal = &mut ctx.accounts.appeal;
+    let agent_account = &mut ctx.accounts.agent_account;
+
+    require!(
+        appeal.status == APPEAL_STATUS_OPEN,
+        ErrorCode::AppealNotOpen
+    );
+    require!(
+        ctx.accounts.restored_claim.is_none()
+            || (uphold && appeal.subject_kind == APPEAL_SUBJECT_REJECTION),
+        ErrorCode::InvalidAppealSubject
+    );
+
+    if uphold {
+        let restored = match appeal.subject_kind {
+            APPEAL_SUBJECT_SLASH => {
+                let slash_record = ctx
+                    .accounts
+                    .slash_record
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidAppealSubject)?;
+                agent_account.clips_balance = agent_account
+                    .clips_balance
+                    .checked_add(slash_record.amount)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                agent_account.clips_slashed = agent_account
+                    .clips_slashed
+                    .checked_sub(slash_record.amount)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                protocol.clips_slashed = protocol
+                    .clips_slashed
+                    .checked_sub(slash_record.amount)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                slash_record.amount
+            }
+            APPEAL_SUBJECT_CLAIM => {
+                let claim = ctx
+                    .accounts
+                    .claim
+                    .as_mut()
+                    .ok_or(ErrorCode::InvalidAppealSubject)?;
+                let restored = restore_clips(
+                    protocol,
+                    agent_account,
+                    emission_policy,
+                    claim.clips_clawed_back,
+                    now,
+                )?;
+                claim.status = CLAIM_STATUS_APPROVED;
+                claim.payout_frozen = false;
+                claim.clips_clawed_back = 0;
+                restored
+            }
+            APPEAL_SUBJECT_REJECTION => {
+                let rejection = ctx
+                    .accounts
+                    .rejection
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidAppealSubject)?;
+                let task = ctx
+                    .accounts
+                    .task
+                    .as_mut()
+                    .ok_or(ErrorCode::InvalidAppealSubject)?;
+                require!(
+                    task.task_id == rejection.task_id,
+                    ErrorCode::InvalidAppealSubject
+                );
+                let claim = ctx
+                    .accounts
+                    .restored_claim
+                    .as_mut()
+                    .ok_or(ErrorCode::InvalidAppealSubject)?;
+
+                require!(
+                    task.current_claims < task.max_claims,
+                    ErrorCode::TaskFullyClaimed
+                );
+
+                task.current_claims = task
+                    .current_claims
+                    .checked_add(1)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                let restored = restore_clips(
+                    protocol,
+                    agent_account,
+                    emission_policy,
+                    rejection.clips_awarded,
+                    now,
+                )?;
+
+                claim.bump = ctx
+                    .bumps
+                    .restored_claim
+                    .ok_or(ErrorCode::InvalidAppealSubject)?;
+                claim.layout_version = ACCOUNT_LAYOUT_V1;
+                claim.task_id = rejection.task_id;
+                claim.agent = rejection.agent;
+                claim.proof_cid = rejection.proof_cid;
+                claim.clips_awarded = restored;
+                claim.completed_at = rejection.submitted_at;
+                claim.completion_index = rejection.completion_index;
+                claim.status = CLAIM_STATUS_APPROVED;
+                claim.finalizes_at = now;
+                claim.flag_count = 0;
+                claim.payout_frozen = false;
+                claim.reward_boost_bps = rejection.reward_boost_bps;
+                claim.clips_clawed_back = 0;
+                claim.reserved = [0; CLAIM_RESERVED_BYTES];
+                restored
+            }
+            _ => return err!(ErrorCode::InvalidAppealSubject),
+        };
+        appeal.clips_restored = restored;
+        appeal.status = APPEAL_STATUS_UPHELD;
+    } else {
+        appeal.status = APPEAL_STATUS_DENIED;
+    }
+    appeal.resolved_at = now;
+
+    Ok(())
+}
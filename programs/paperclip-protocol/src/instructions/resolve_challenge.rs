@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        AGENT_SEED, BPS_DENOMINATOR, CHALLENGE_SEED, CHALLENGE_STATUS_DISMISSED,
+        CHALLENGE_STATUS_OPEN, CHALLENGE_STATUS_UPHELD, CLAIM_STATUS_REVOKED, DISPUTE_CONFIG_SEED,
+        PROTOCOL_SEED,
+    },
+    error::ErrorCode,
+    state::{AgentAccount, ChallengeRecord, ClaimRecord, DisputeConfig, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct ResolveChallenge<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        seeds = [DISPUTE_CONFIG_SEED],
+        bump = dispute_config.bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(mut)]
+    pub claim: Account<'info, ClaimRecord>,
+    #[account(
+        mut,
+        seeds = [CHALLENGE_SEED, claim.key().as_ref()],
+        bump = challenge.bump
+    )]
+    pub challenge: Account<'info, ChallengeRecord>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, challenge.claimant.as_ref()],
+        bump = claimant_agent.bump
+    )]
+    pub claimant_agent: Account<'info, AgentAccount>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, challenge.challenger.as_ref()],
+        bump = challenger_agent.bump
+    )]
+    pub challenger_agent: Account<'info, AgentAccount>,
+    pub authority: Signer<'info>,
+}
+
+/// Upholding revokes the claim: its reward is clawed back from the claimant, the
+/// challenger gets the stake back plus `challenger_reward_bps` of the clawback,
+/// and the remainder is burned. Dismissing forfeits the stake to the claimant
+/// or burns it, per `DisputeConfig`.
+pub fn handler(ctx: Context<ResolveChallenge>, uphold: bool) -> Result<()> {
+    let dispute_config = &ctx.accounts.dispute_config;
+    let protocol = &mut ctx.accounts.protocol;
+    let claim = &mut ctx.accounts.claim;
+    let challenge = &mut ctx.accounts.challenge;
+    let claimant_agent = &mut ctx.accounts.claimant_agent;
+    let challenger_agent = &mut ctx.accounts.challenger_agent;
+
+    require!(
+        challenge.status == CHALLENGE_STATUS_OPEN,
+        ErrorCode::ChallengeNotOpen
+    );
+
+    let burned = if uphold {
+        // The claimant may have staked part of the reward elsewhere since; claw
+        // back what is left.
+        let clawback = claim.clips_awarded.min(claimant_agent.clips_balance);
+        claimant_agent.clips_balance -= clawback;
+        claimant_agent.tasks_completed = claimant_agent.tasks_completed.saturating_sub(1);
+
+        let bounty = clawback
+            .checked_mul(u64::from(dispute_config.challenger_reward_bps))
+            .ok_or(ErrorCode::MathOverflow)?
+            / BPS_DENOMINATOR;
+        challenger_agent.clips_balance = challenger_agent
+            .clips_balance
+            .checked_add(challenge.stake)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_add(bounty)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        claim.status = CLAIM_STATUS_REVOKED;
+        claim.clips_clawed_back = clawback;
+        challenge.status = CHALLENGE_STATUS_UPHELD;
+        clawback - bounty
+    } else {
+        challenge.status = CHALLENGE_STATUS_DISMISSED;
+        if dispute_config.forfeit_stake_to_claimant {
+            claimant_agent.clips_balance = claimant_agent
+                .clips_balance
+                .checked_add(challenge.stake)
+                .ok_or(ErrorCode::MathOverflow)?;
+            0
+        } else {
+            challenge.stake
+        }
+    };
+
+    protocol.clips_burned = protocol
+        .clips_burned
+        .checked_add(burned)
+        .ok_or(ErrorCode::MathOverflow)?;
+    challenge.resolved_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PROTOCOL_SEED, PUBLISHER_SEED},
+    error::ErrorCode,
+    state::{ProtocolState, PublisherRole},
+};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RevokePublisherRole<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PUBLISHER_SEED, wallet.as_ref()],
+        bump = publisher_role.bump
+    )]
+    pub publisher_role: Account<'info, PublisherRole>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<RevokePublisherRole>, _wallet: Pubkey) -> Result<()> {
+    Ok(())
+}
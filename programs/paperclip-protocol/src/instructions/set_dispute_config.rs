@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, BPS_DENOMINATOR, DISPUTE_CONFIG_RESERVED_BYTES, DISPUTE_CONFIG_SEED,
+        PROTOCOL_SEED,
+    },
+    error::ErrorCode,
+    state::{DisputeConfig, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct SetDisputeConfig<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DisputeConfig::SPACE,
+        seeds = [DISPUTE_CONFIG_SEED],
+        bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetDisputeConfig>,
+    min_challenger_tier: u8,
+    min_challenge_stake: u64,
+    challenger_reward_bps: u16,
+    forfeit_stake_to_claimant: bool,
+    flag_freeze_threshold: u16,
+) -> Result<()> {
+    require!(
+        u64::from(challenger_reward_bps) <= BPS_DENOMINATOR,
+        ErrorCode::InvalidBasisPoints
+    );
+
+    let dispute_config = &mut ctx.accounts.dispute_config;
+    dispute_config.bump = ctx.bumps.dispute_config;
+    dispute_config.layout_version = ACCOUNT_LAYOUT_V1;
+    dispute_config.min_challenger_tier = min_challenger_tier;
+    dispute_config.min_challenge_stake = min_challenge_stake;
+    dispute_config.challenger_reward_bps = challenger_reward_bps;
+    dispute_config.forfeit_stake_to_claimant = forfeit_stake_to_claimant;
+    dispute_config.flag_freeze_threshold = flag_freeze_threshold;
+    dispute_config.reserved = [0; DISPUTE_CONFIG_RESERVED_BYTES];
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, EMISSION_POLICY_RESERVED_BYTES, EMISSION_POLICY_SEED, PROTOCOL_SEED,
+    },
+    error::ErrorCode,
+    state::{EmissionPolicy, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct SetEmissionPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = EmissionPolicy::SPACE,
+        seeds = [EMISSION_POLICY_SEED],
+        bump
+    )]
+    pub emission_policy: Account<'info, EmissionPolicy>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates or retunes the emission policy. Epoch zero starts on the first call;
+/// later calls keep the epoch and halving counts. Changing the epoch duration
+/// restarts the current epoch now, with a fresh budget, so elapsed time isn't
+/// re-counted in the new unit.
+pub fn handler(
+    ctx: Context<SetEmissionPolicy>,
+    supply_cap: u64,
+    epoch_budget: u64,
+    epoch_duration_seconds: u32,
+    halving_interval_epochs: u32,
+    pro_rate: bool,
+) -> Result<()> {
+    require!(epoch_duration_seconds > 0, ErrorCode::InvalidEmissionPolicy);
+
+    let now = Clock::get()?.unix_timestamp;
+    let protocol = &mut ctx.accounts.protocol;
+    let emission_policy = &mut ctx.accounts.emission_policy;
+    if emission_policy.layout_version == 0 {
+        emission_policy.bump = ctx.bumps.emission_policy;
+        emission_policy.layout_version = ACCOUNT_LAYOUT_V1;
+        emission_policy.genesis_at = now;
+        emission_policy.activated_at = now;
+        emission_policy.current_epoch = 0;
+        emission_policy.epoch_emitted = 0;
+        emission_policy.halvings_applied = 0;
+        emission_policy.total_allocated = protocol.total_clips_distributed;
+        emission_policy.reserved = [0; EMISSION_POLICY_RESERVED_BYTES];
+    } else if emission_policy.epoch_duration_seconds != epoch_duration_seconds {
+        // Settle the epoch under the old duration before rebasing the clock.
+        emission_policy.advance(protocol, now)?;
+        let elapsed = i64::from(emission_policy.current_epoch)
+            .checked_mul(i64::from(epoch_duration_seconds))
+            .ok_or(ErrorCode::MathOverflow)?;
+        emission_policy.genesis_at = now.checked_sub(elapsed).ok_or(ErrorCode::MathOverflow)?;
+        emission_policy.epoch_emitted = 0;
+    }
+    emission_policy.supply_cap = supply_cap;
+    emission_policy.epoch_budget = epoch_budget;
+    emission_policy.epoch_duration_seconds = epoch_duration_seconds;
+    emission_policy.halving_interval_epochs = halving_interval_epochs;
+    emission_policy.pro_rate = pro_rate;
+
+    protocol.emission_policy_active = true;
+
+    Ok(())
+}
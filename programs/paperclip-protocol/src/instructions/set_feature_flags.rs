@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEATURE_FLAGS_MASK, PROTOCOL_SEED},
+    error::ErrorCode,
+    state::ProtocolState,
+};
+
+#[derive(Accounts)]
+pub struct SetFeatureFlags<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetFeatureFlags>, feature_flags: u32) -> Result<()> {
+    require!(
+        feature_flags & !FEATURE_FLAGS_MASK == 0,
+        ErrorCode::InvalidFeatureFlags
+    );
+
+    ctx.accounts.protocol.feature_flags = feature_flags;
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ACCOUNT_LAYOUT_V1, PROTOCOL_SEED, REWARD_EVENT_RESERVED_BYTES, REWARD_EVENT_SEED},
+    error::ErrorCode,
+    state::{ProtocolState, RewardEvent},
+};
+
+#[derive(Accounts)]
+#[instruction(event_id: u32)]
+pub struct SetRewardEvent<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RewardEvent::SPACE,
+        seeds = [REWARD_EVENT_SEED, event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_event: Account<'info, RewardEvent>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates or reschedules a reward event. Moving `ends_at` to the current time
+/// ends a running event early.
+pub fn handler(
+    ctx: Context<SetRewardEvent>,
+    event_id: u32,
+    starts_at: i64,
+    ends_at: i64,
+    multiplier_bps: u32,
+    min_task_id: u32,
+    max_task_id: u32,
+) -> Result<()> {
+    require!(ends_at > starts_at, ErrorCode::InvalidRewardEvent);
+    require!(multiplier_bps > 0, ErrorCode::InvalidRewardEvent);
+    require!(min_task_id <= max_task_id, ErrorCode::InvalidRewardEvent);
+
+    let reward_event = &mut ctx.accounts.reward_event;
+    if reward_event.layout_version == 0 {
+        reward_event.bump = ctx.bumps.reward_event;
+        reward_event.layout_version = ACCOUNT_LAYOUT_V1;
+        reward_event.event_id = event_id;
+        reward_event.reserved = [0; REWARD_EVENT_RESERVED_BYTES];
+    }
+    reward_event.starts_at = starts_at;
+    reward_event.ends_at = ends_at;
+    reward_event.multiplier_bps = multiplier_bps;
+    reward_event.min_task_id = min_task_id;
+    reward_event.max_task_id = max_task_id;
+
+    Ok(())
+}
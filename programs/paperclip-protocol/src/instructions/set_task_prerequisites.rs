@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, MAX_PREREQUISITES, MAX_PREREQ_CHAIN_DEPTH, NO_PREREQ_TASK_ID,
+        PREREQ_MODE_ALL, PREREQ_MODE_ANY, PREREQ_RESERVED_BYTES, PREREQ_SEED, PROTOCOL_SEED,
+        TASK_SEED,
+    },
+    error::ErrorCode,
+    instructions::create_task::load_task_record,
+    state::{ProtocolState, TaskPrerequisites, TaskRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct SetTaskPrerequisites<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TaskPrerequisites::SPACE,
+        seeds = [PREREQ_SEED, task_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub prerequisites: Account<'info, TaskPrerequisites>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the task's prerequisite set. An empty `task_ids` list turns the set
+/// off again. Listed tasks must exist and must not depend on this task; see
+/// `validate_prerequisite_graph` for the accounts this needs.
+pub fn handler(
+    ctx: Context<SetTaskPrerequisites>,
+    task_id: u32,
+    mode: u8,
+    task_ids: Vec<u32>,
+) -> Result<()> {
+    require!(
+        mode == PREREQ_MODE_ALL || mode == PREREQ_MODE_ANY,
+        ErrorCode::InvalidPrerequisiteSet
+    );
+    require!(
+        task_ids.len() <= MAX_PREREQUISITES,
+        ErrorCode::InvalidPrerequisiteSet
+    );
+    for (index, &required_task_id) in task_ids.iter().enumerate() {
+        require!(
+            required_task_id != task_id,
+            ErrorCode::InvalidTaskPrerequisite
+        );
+        require!(
+            required_task_id != NO_PREREQ_TASK_ID && !task_ids[..index].contains(&required_task_id),
+            ErrorCode::InvalidPrerequisiteSet
+        );
+    }
+    validate_prerequisite_graph(task_id, &task_ids, ctx.remaining_accounts, ctx.program_id)?;
+
+    let prerequisites = &mut ctx.accounts.prerequisites;
+    prerequisites.bump = ctx.bumps.prerequisites;
+    prerequisites.layout_version = ACCOUNT_LAYOUT_V1;
+    prerequisites.task_id = task_id;
+    prerequisites.mode = mode;
+    prerequisites.count = task_ids.len() as u8;
+    prerequisites.task_ids = [NO_PREREQ_TASK_ID; MAX_PREREQUISITES];
+    prerequisites.task_ids[..task_ids.len()].copy_from_slice(&task_ids);
+    prerequisites.reserved = [0; PREREQ_RESERVED_BYTES];
+
+    ctx.accounts.task.has_prerequisite_set = !task_ids.is_empty();
+
+    Ok(())
+}
+
+/// Walks everything the listed tasks depend on, through both `required_task_id`
+/// and prerequisite sets, and fails if the walk reaches `task_id`. Listed tasks
+/// must exist; deeper links whose record no longer exists end that branch.
+///
+/// Tasks are visited depth-first, each once, starting with `task_ids` in order;
+/// a task's `required_task_id` is visited before its set's entries.
+/// `remaining_accounts` carries, per visited task, its `TaskRecord` followed by
+/// its `TaskPrerequisites` if it has a prerequisite set.
+fn validate_prerequisite_graph(
+    task_id: u32,
+    task_ids: &[u32],
+    remaining_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let mut accounts = remaining_accounts.iter();
+    let mut visited: Vec<u32> = Vec::with_capacity(MAX_PREREQ_CHAIN_DEPTH);
+    let mut pending: Vec<(u32, bool)> = task_ids.iter().rev().map(|&id| (id, true)).collect();
+
+    while let Some((next_task_id, listed)) = pending.pop() {
+        require!(next_task_id != task_id, ErrorCode::PrerequisiteCycle);
+        if visited.contains(&next_task_id) {
+            continue;
+        }
+        require!(
+            visited.len() < MAX_PREREQ_CHAIN_DEPTH,
+            ErrorCode::PrerequisiteChainTooDeep
+        );
+        visited.push(next_task_id);
+
+        let account = accounts.next().ok_or(ErrorCode::MissingPrerequisiteTask)?;
+        let Some(task) = load_task_record(account, next_task_id, program_id)? else {
+            require!(!listed, ErrorCode::PrerequisiteTaskNotFound);
+            continue;
+        };
+
+        if task.has_prerequisite_set {
+            let set_account = accounts.next().ok_or(ErrorCode::MissingPrerequisiteTask)?;
+            let expected_set_pda = Pubkey::find_program_address(
+                &[PREREQ_SEED, next_task_id.to_le_bytes().as_ref()],
+                program_id,
+            )
+            .0;
+            require_keys_eq!(
+                *set_account.key,
+                expected_set_pda,
+                ErrorCode::InvalidPrerequisiteAccount
+            );
+            let data = set_account
+                .try_borrow_data()
+                .map_err(|_| error!(ErrorCode::InvalidPrerequisiteAccount))?;
+            let mut slice: &[u8] = &data;
+            let set = TaskPrerequisites::try_deserialize(&mut slice)
+                .map_err(|_| error!(ErrorCode::InvalidPrerequisiteAccount))?;
+            pending.extend(set.required_task_ids().iter().rev().map(|&id| (id, false)));
+        }
+        if task.required_task_id != NO_PREREQ_TASK_ID {
+            pending.push((task.required_task_id, false));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PROTOCOL_SEED, TASK_SEED},
+    error::ErrorCode,
+    state::{ProtocolState, TaskRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct SetTaskRepeatable<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    pub authority: Signer<'info>,
+}
+
+/// Switches a task into repeatable mode. Only allowed before the first claim so
+/// single-claim and per-completion claims never mix on one task.
+pub fn handler(
+    ctx: Context<SetTaskRepeatable>,
+    _task_id: u32,
+    cooldown_seconds: u32,
+    max_completions: u16,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    require!(task.current_claims == 0, ErrorCode::TaskAlreadyClaimed);
+
+    task.is_repeatable = true;
+    task.cooldown_seconds = cooldown_seconds;
+    task.max_completions = max_completions;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        BPS_DENOMINATOR, PROTOCOL_SEED, REWARD_CURVE_BY_CLAIMS, REWARD_CURVE_BY_TIME,
+        REWARD_CURVE_EXPONENTIAL, REWARD_CURVE_LINEAR, REWARD_CURVE_NONE, TASK_SEED,
+    },
+    error::ErrorCode,
+    state::{ProtocolState, TaskRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct SetTaskRewardCurve<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    pub authority: Signer<'info>,
+}
+
+/// The curve starts from the task's resolved reward (fixed or base-unit) and
+/// decays toward `reward_floor_bps` of it, so early claimants earn more and the
+/// total cost of the task is bounded. `REWARD_CURVE_NONE` pays the flat reward.
+pub fn handler(
+    ctx: Context<SetTaskRewardCurve>,
+    _task_id: u32,
+    reward_curve: u8,
+    reward_curve_basis: u8,
+    reward_floor_bps: u16,
+    reward_curve_period: u32,
+) -> Result<()> {
+    require!(
+        matches!(
+            reward_curve,
+            REWARD_CURVE_NONE | REWARD_CURVE_LINEAR | REWARD_CURVE_EXPONENTIAL
+        ),
+        ErrorCode::InvalidRewardCurve
+    );
+    require!(
+        reward_curve_basis == REWARD_CURVE_BY_CLAIMS || reward_curve_basis == REWARD_CURVE_BY_TIME,
+        ErrorCode::InvalidRewardCurve
+    );
+    require!(
+        u64::from(reward_floor_bps) <= BPS_DENOMINATOR,
+        ErrorCode::InvalidRewardCurve
+    );
+    require!(
+        reward_curve == REWARD_CURVE_NONE || reward_curve_period > 0,
+        ErrorCode::InvalidRewardCurve
+    );
+
+    let task = &mut ctx.accounts.task;
+    task.reward_curve = reward_curve;
+    task.reward_curve_basis = reward_curve_basis;
+    task.reward_floor_bps = reward_floor_bps;
+    task.reward_curve_period = reward_curve_period;
+
+    Ok(())
+}
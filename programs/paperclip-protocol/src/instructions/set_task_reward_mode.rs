@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PROTOCOL_SEED, REWARD_MODE_BASE_UNITS, REWARD_MODE_FIXED, TASK_SEED},
+    error::ErrorCode,
+    state::{ProtocolState, TaskRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct SetTaskRewardMode<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    pub authority: Signer<'info>,
+}
+
+/// In base-unit mode the reward tracks `base_reward_unit`, so halvings and
+/// `update_economy` reach the task without editing it. `reward_clips` is kept
+/// for switching back to fixed mode.
+pub fn handler(
+    ctx: Context<SetTaskRewardMode>,
+    _task_id: u32,
+    reward_mode: u8,
+    reward_base_unit_bps: u32,
+) -> Result<()> {
+    require!(
+        reward_mode == REWARD_MODE_FIXED || reward_mode == REWARD_MODE_BASE_UNITS,
+        ErrorCode::InvalidRewardMode
+    );
+
+    let task = &mut ctx.accounts.task;
+    task.reward_mode = reward_mode;
+    task.reward_base_unit_bps = reward_base_unit_bps;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PROTOCOL_SEED, TASK_SEED, VERIFICATION_VALIDATOR},
+    error::ErrorCode,
+    state::{ProtocolState, TaskRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct SetTaskValidator<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    pub authority: Signer<'info>,
+}
+
+/// Puts the task in validator mode: later proofs wait for `validator` to approve
+/// or reject them.
+pub fn handler(ctx: Context<SetTaskValidator>, _task_id: u32, validator: Pubkey) -> Result<()> {
+    require!(validator != Pubkey::default(), ErrorCode::InvalidValidator);
+
+    let task = &mut ctx.accounts.task;
+    task.verification_mode = VERIFICATION_VALIDATOR;
+    task.challenge_window_seconds = 0;
+    task.validator = validator;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        PROTOCOL_SEED, TASK_SEED, VERIFICATION_INSTANT, VERIFICATION_OPTIMISTIC,
+        VERIFICATION_VALIDATOR,
+    },
+    error::ErrorCode,
+    state::{ProtocolState, TaskRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct SetTaskVerification<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    pub authority: Signer<'info>,
+}
+
+/// Existing claims keep the status they were submitted with; the new mode only
+/// applies to later submissions. Validator mode is set with `set_task_validator`,
+/// and can't be left while the task has unsettled claims.
+pub fn handler(
+    ctx: Context<SetTaskVerification>,
+    _task_id: u32,
+    verification_mode: u8,
+    challenge_window_seconds: u32,
+) -> Result<()> {
+    require!(
+        verification_mode == VERIFICATION_INSTANT || verification_mode == VERIFICATION_OPTIMISTIC,
+        ErrorCode::InvalidVerificationMode
+    );
+
+    let task = &mut ctx.accounts.task;
+    require!(
+        task.verification_mode != VERIFICATION_VALIDATOR || task.unsettled_claims == 0,
+        ErrorCode::TaskHasUnsettledClaims
+    );
+    task.verification_mode = verification_mode;
+    task.challenge_window_seconds = challenge_window_seconds;
+    task.validator = Pubkey::default();
+
+    Ok(())
+}
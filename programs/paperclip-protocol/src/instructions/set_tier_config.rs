@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, PROTOCOL_SEED, TIER_CONFIG_RESERVED_BYTES, TIER_CONFIG_SEED, TIER_COUNT,
+    },
+    error::ErrorCode,
+    state::{ProtocolState, TierConfig},
+};
+
+#[derive(Accounts)]
+pub struct SetTierConfig<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TierConfig::SPACE,
+        seeds = [TIER_CONFIG_SEED],
+        bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetTierConfig>,
+    min_clips_balance: [u64; TIER_COUNT],
+    min_tasks_completed: [u32; TIER_COUNT],
+    promotion_cooldown_seconds: u32,
+    decay_after_seconds: [u32; TIER_COUNT],
+) -> Result<()> {
+    require!(
+        min_clips_balance.windows(2).all(|pair| pair[0] <= pair[1])
+            && min_tasks_completed
+                .windows(2)
+                .all(|pair| pair[0] <= pair[1]),
+        ErrorCode::InvalidTierThresholds
+    );
+
+    let tier_config = &mut ctx.accounts.tier_config;
+    tier_config.bump = ctx.bumps.tier_config;
+    tier_config.layout_version = ACCOUNT_LAYOUT_V1;
+    tier_config.min_clips_balance = min_clips_balance;
+    tier_config.min_tasks_completed = min_tasks_completed;
+    tier_config.promotion_cooldown_seconds = promotion_cooldown_seconds;
+    tier_config.decay_after_seconds = decay_after_seconds;
+    tier_config.reserved = [0; TIER_CONFIG_RESERVED_BYTES];
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ECONOMY_CONFIG_SEED, PROTOCOL_SEED, TIER_COUNT},
+    error::ErrorCode,
+    state::{EconomyConfig, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct SetTierMultipliers<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [ECONOMY_CONFIG_SEED],
+        bump = economy_config.bump
+    )]
+    pub economy_config: Account<'info, EconomyConfig>,
+    pub authority: Signer<'info>,
+}
+
+/// Applies to proofs submitted from now on; claims already recorded keep the
+/// `clips_awarded` they were submitted with.
+pub fn handler(
+    ctx: Context<SetTierMultipliers>,
+    tier_reward_multiplier_bps: [u16; TIER_COUNT],
+) -> Result<()> {
+    require!(
+        tier_reward_multiplier_bps.iter().all(|&bps| bps > 0),
+        ErrorCode::InvalidTierMultiplier
+    );
+
+    ctx.accounts.economy_config.tier_reward_multiplier_bps = tier_reward_multiplier_bps;
+
+    Ok(())
+}
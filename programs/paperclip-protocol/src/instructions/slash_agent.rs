@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ACCOUNT_LAYOUT_V1, AGENT_SEED, PROTOCOL_SEED, SLASH_RESERVED_BYTES, SLASH_SEED},
+    error::ErrorCode,
+    state::{AgentAccount, ProtocolState, SlashRecord},
+};
+
+#[derive(Accounts)]
+pub struct SlashAgent<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_account.wallet.as_ref()],
+        bump = agent_account.bump
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = SlashRecord::SPACE,
+        seeds = [
+            SLASH_SEED,
+            agent_account.wallet.as_ref(),
+            &agent_account.slash_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SlashAgent>,
+    amount: u64,
+    reason_code: u8,
+    evidence_cid: [u8; 64],
+) -> Result<()> {
+    let protocol = &mut ctx.accounts.protocol;
+    let agent_account = &mut ctx.accounts.agent_account;
+
+    require!(amount > 0, ErrorCode::InvalidSlashAmount);
+    require!(
+        agent_account.clips_balance >= amount,
+        ErrorCode::InsufficientClips
+    );
+
+    let slash_record = &mut ctx.accounts.slash_record;
+    slash_record.bump = ctx.bumps.slash_record;
+    slash_record.layout_version = ACCOUNT_LAYOUT_V1;
+    slash_record.agent = agent_account.wallet;
+    slash_record.index = agent_account.slash_count;
+    slash_record.amount = amount;
+    slash_record.reason_code = reason_code;
+    slash_record.evidence_cid = evidence_cid;
+    slash_record.slashed_by = ctx.accounts.authority.key();
+    slash_record.created_at = Clock::get()?.unix_timestamp;
+    slash_record.reserved = [0; SLASH_RESERVED_BYTES];
+
+    agent_account.clips_balance -= amount;
+    agent_account.clips_slashed = agent_account
+        .clips_slashed
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    agent_account.slash_count = agent_account
+        .slash_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    protocol.clips_slashed = protocol
+        .clips_slashed
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
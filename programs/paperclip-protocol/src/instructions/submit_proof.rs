@@ -2,11 +2,17 @@ use anchor_lang::prelude::*;
 
 use crate::{
     constants::{
-        ACCOUNT_LAYOUT_V1, AGENT_SEED, CLAIM_RESERVED_BYTES, CLAIM_SEED, NO_PREREQ_TASK_ID,
-        PROTOCOL_SEED, TASK_SEED,
+        ACCOUNT_LAYOUT_V1, AGENT_SEED, CLAIM_RESERVED_BYTES, CLAIM_SEED, CLAIM_STATUS_APPROVED,
+        CLAIM_STATUS_AWAITING_VALIDATOR, CLAIM_STATUS_PENDING, ECONOMY_CONFIG_SEED,
+        EMISSION_POLICY_SEED, FEATURE_PROOF_SUBMISSION_DISABLED, NO_PREREQ_TASK_ID,
+        PREREQ_MODE_ANY, PREREQ_SEED, PROTOCOL_SEED, TASK_SEED, VERIFICATION_OPTIMISTIC,
+        VERIFICATION_VALIDATOR,
     },
     error::ErrorCode,
-    state::{AgentAccount, ClaimRecord, ProtocolState, TaskRecord},
+    state::{
+        scale_bps, AgentAccount, ClaimRecord, EconomyConfig, EmissionPolicy, ProtocolState,
+        RewardEvent, TaskPrerequisites, TaskRecord,
+    },
 };
 
 #[derive(Accounts)]
@@ -18,6 +24,18 @@ pub struct SubmitProof<'info> {
         bump = protocol.bump
     )]
     pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [EMISSION_POLICY_SEED],
+        bump = emission_policy.bump
+    )]
+    pub emission_policy: Option<Account<'info, EmissionPolicy>>,
+    pub reward_event: Option<Account<'info, RewardEvent>>,
+    #[account(
+        seeds = [ECONOMY_CONFIG_SEED],
+        bump = economy_config.bump
+    )]
+    pub economy_config: Account<'info, EconomyConfig>,
     #[account(
         mut,
         seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
@@ -46,101 +64,329 @@ pub struct SubmitProof<'info> {
 pub fn handler(ctx: Context<SubmitProof>, task_id: u32, proof_cid: [u8; 64]) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
 
-    {
-        let task = &ctx.accounts.task;
-        let agent_account = &ctx.accounts.agent_account;
+    require!(
+        !ctx.accounts.task.is_repeatable,
+        ErrorCode::TaskIsRepeatable
+    );
+    validate_submission(
+        &ctx.accounts.protocol,
+        &ctx.accounts.task,
+        &ctx.accounts.agent_account,
+        &ctx.accounts.agent.key(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        now,
+    )?;
+
+    let emission_policy = ctx
+        .accounts
+        .protocol
+        .emission_policy(ctx.accounts.emission_policy.as_deref_mut())?;
+    let settlement = settle_submission(
+        &mut ctx.accounts.protocol,
+        &mut ctx.accounts.task,
+        &mut ctx.accounts.agent_account,
+        emission_policy,
+        ctx.accounts.reward_event.as_deref(),
+        &ctx.accounts.economy_config,
+        now,
+    )?;
+
+    let claim = &mut ctx.accounts.claim;
+    claim.bump = ctx.bumps.claim;
+    claim.layout_version = ACCOUNT_LAYOUT_V1;
+    claim.task_id = task_id;
+    claim.agent = ctx.accounts.agent.key();
+    claim.proof_cid = proof_cid;
+    claim.clips_awarded = settlement.clips;
+    claim.completed_at = now;
+    claim.completion_index = 0;
+    claim.status = settlement.status;
+    claim.finalizes_at = settlement.finalizes_at;
+    claim.flag_count = 0;
+    claim.payout_frozen = false;
+    claim.reward_boost_bps = settlement.boost_bps;
+    claim.clips_clawed_back = 0;
+    claim.reserved = [0; CLAIM_RESERVED_BYTES];
 
+    Ok(())
+}
+
+/// Checks shared by every proof submission path: protocol switches, agent
+/// freeze and tier, prerequisites, task status and claim capacity.
+///
+/// `remaining_accounts` carries, in order: the claim for `required_task_id` (if
+/// set), then the task's `TaskPrerequisites` account followed by one claim PDA
+/// per listed task (if the task has a prerequisite set).
+pub(crate) fn validate_submission(
+    protocol: &ProtocolState,
+    task: &TaskRecord,
+    agent_account: &AgentAccount,
+    agent: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    require!(!protocol.paused, ErrorCode::ProtocolPaused);
+    require!(
+        !protocol.is_feature_disabled(FEATURE_PROOF_SUBMISSION_DISABLED),
+        ErrorCode::ProofSubmissionDisabled
+    );
+    require!(!agent_account.is_frozen(now), ErrorCode::AgentFrozen);
+
+    require!(
+        agent_account.efficiency_tier >= task.min_tier,
+        ErrorCode::TierTooLow
+    );
+
+    let mut remaining = remaining_accounts.iter();
+
+    if task.required_task_id != NO_PREREQ_TASK_ID {
+        let prerequisite_account = remaining
+            .next()
+            .ok_or(ErrorCode::MissingRequiredTaskProof)?;
         require!(
-            agent_account.efficiency_tier >= task.min_tier,
-            ErrorCode::TierTooLow
+            has_prerequisite_claim(
+                prerequisite_account,
+                task.required_task_id,
+                agent,
+                program_id
+            )?,
+            ErrorCode::MissingRequiredTaskProof
         );
+    }
 
-        if task.required_task_id != NO_PREREQ_TASK_ID {
-            let prerequisite_account = ctx
-                .remaining_accounts
-                .first()
-                .ok_or(ErrorCode::MissingRequiredTaskProof)?;
+    if task.has_prerequisite_set {
+        let set_account = remaining
+            .next()
+            .ok_or(ErrorCode::InvalidPrerequisiteAccount)?;
+        let expected_set_pda = Pubkey::find_program_address(
+            &[PREREQ_SEED, task.task_id.to_le_bytes().as_ref()],
+            program_id,
+        )
+        .0;
+        require_keys_eq!(
+            *set_account.key,
+            expected_set_pda,
+            ErrorCode::InvalidPrerequisiteAccount
+        );
+        require!(
+            *set_account.owner == *program_id,
+            ErrorCode::InvalidPrerequisiteAccount
+        );
+        let data = set_account
+            .try_borrow_data()
+            .map_err(|_| error!(ErrorCode::InvalidPrerequisiteAccount))?;
+        let mut slice: &[u8] = &data;
+        let prerequisites = TaskPrerequisites::try_deserialize(&mut slice)
+            .map_err(|_| error!(ErrorCode::InvalidPrerequisiteAccount))?;
 
-            let required_task_id_bytes = task.required_task_id.to_le_bytes();
-            let expected_claim_pda = Pubkey::find_program_address(
-                &[
-                    CLAIM_SEED,
-                    required_task_id_bytes.as_ref(),
-                    ctx.accounts.agent.key().as_ref(),
-                ],
-                ctx.program_id,
-            )
-            .0;
-
-            require_keys_eq!(
-                *prerequisite_account.key,
-                expected_claim_pda,
-                ErrorCode::InvalidPrerequisiteAccount
-            );
-
-            require!(
-                *prerequisite_account.owner == *ctx.program_id,
-                ErrorCode::MissingRequiredTaskProof
-            );
-
-            let data = prerequisite_account
-                .try_borrow_data()
-                .map_err(|_| error!(ErrorCode::MissingRequiredTaskProof))?;
-            let mut slice: &[u8] = &data;
-            let prerequisite_claim = ClaimRecord::try_deserialize(&mut slice)
-                .map_err(|_| error!(ErrorCode::MissingRequiredTaskProof))?;
-
-            require!(
-                prerequisite_claim.task_id == task.required_task_id,
-                ErrorCode::InvalidPrerequisiteAccount
-            );
-            require_keys_eq!(
-                prerequisite_claim.agent,
-                ctx.accounts.agent.key(),
-                ErrorCode::InvalidPrerequisiteAccount
-            );
+        let mut satisfied = 0usize;
+        for &required_task_id in prerequisites.required_task_ids() {
+            let claim_account = remaining
+                .next()
+                .ok_or(ErrorCode::MissingRequiredTaskProof)?;
+            if has_prerequisite_claim(claim_account, required_task_id, agent, program_id)? {
+                satisfied += 1;
+            }
         }
 
-        require!(task.is_active, ErrorCode::TaskInactive);
-        require!(
-            task.current_claims < task.max_claims,
-            ErrorCode::TaskFullyClaimed
+        let required = match prerequisites.mode {
+            PREREQ_MODE_ANY => 1,
+            _ => prerequisites.required_task_ids().len(),
+        };
+        require!(satisfied >= required, ErrorCode::MissingRequiredTaskProof);
+    }
+
+    require!(task.is_active, ErrorCode::TaskInactive);
+    require!(
+        task.starts_at == 0 || now >= task.starts_at,
+        ErrorCode::TaskNotStarted
+    );
+    require!(
+        task.ends_at == 0 || now < task.ends_at,
+        ErrorCode::TaskExpired
+    );
+    require!(
+        task.current_claims < task.max_claims,
+        ErrorCode::TaskFullyClaimed
+    );
+
+    Ok(())
+}
+
+/// Returns whether `account` is the agent's claim on `required_task_id`. The
+/// account must sit at the expected claim PDA, or, for repeatable prerequisites,
+/// at the agent's first completion (index 0); an empty or foreign-owned account
+/// there, or a claim that is not yet approved, means the prerequisite has not
+/// been completed.
+fn has_prerequisite_claim(
+    account: &AccountInfo,
+    required_task_id: u32,
+    agent: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<bool> {
+    let required_task_id_bytes = required_task_id.to_le_bytes();
+    let expected_claim_pda = Pubkey::find_program_address(
+        &[CLAIM_SEED, required_task_id_bytes.as_ref(), agent.as_ref()],
+        program_id,
+    )
+    .0;
+    if *account.key != expected_claim_pda {
+        let first_completion_pda = Pubkey::find_program_address(
+            &[
+                CLAIM_SEED,
+                required_task_id_bytes.as_ref(),
+                agent.as_ref(),
+                0u16.to_le_bytes().as_ref(),
+            ],
+            program_id,
+        )
+        .0;
+        require_keys_eq!(
+            *account.key,
+            first_completion_pda,
+            ErrorCode::InvalidPrerequisiteAccount
         );
     }
 
-    let task = &mut ctx.accounts.task;
-    let protocol = &mut ctx.accounts.protocol;
-    let agent_account = &mut ctx.accounts.agent_account;
+    if *account.owner != *program_id {
+        return Ok(false);
+    }
+
+    let data = account
+        .try_borrow_data()
+        .map_err(|_| error!(ErrorCode::MissingRequiredTaskProof))?;
+    let mut slice: &[u8] = &data;
+    let Ok(prerequisite_claim) = ClaimRecord::try_deserialize(&mut slice) else {
+        return Ok(false);
+    };
+    if prerequisite_claim.status != CLAIM_STATUS_APPROVED {
+        return Ok(false);
+    }
+
+    require!(
+        prerequisite_claim.task_id == required_task_id,
+        ErrorCode::InvalidPrerequisiteAccount
+    );
+    require_keys_eq!(
+        prerequisite_claim.agent,
+        *agent,
+        ErrorCode::InvalidPrerequisiteAccount
+    );
+
+    Ok(true)
+}
+
+/// Outcome of a submission as recorded on its `ClaimRecord`.
+pub(crate) struct Settlement {
+    pub clips: u64,
+    pub boost_bps: u32,
+    pub status: u8,
+    pub finalizes_at: i64,
+}
+
+/// Counts the claim against the task, then either credits the reward right away
+/// or leaves it pending: until the challenge window has passed for optimistic
+/// tasks, or until the task's validator approves it. A passed reward event
+/// must cover the task and the submission time. The reward is then scaled by
+/// the agent's tier multiplier and booked against the emission policy, if any,
+/// at submission time; `clips` is what the agent is, or will be, credited.
+pub(crate) fn settle_submission(
+    protocol: &mut ProtocolState,
+    task: &mut TaskRecord,
+    agent_account: &mut AgentAccount,
+    mut emission_policy: Option<&mut EmissionPolicy>,
+    reward_event: Option<&RewardEvent>,
+    economy_config: &EconomyConfig,
+    now: i64,
+) -> Result<Settlement> {
+    if let Some(policy) = emission_policy.as_deref_mut() {
+        policy.advance(protocol, now)?;
+    }
+    let mut reward = task.resolve_reward(protocol.base_reward_unit, now)?;
+    let mut boost_bps = 0;
+    if let Some(event) = reward_event {
+        require!(
+            event.applies_to(task.task_id, now),
+            ErrorCode::RewardEventNotApplicable
+        );
+        reward = scale_bps(reward, event.multiplier_bps)?;
+        boost_bps = event.multiplier_bps;
+    }
+    let reward = economy_config.tier_reward(reward, agent_account.efficiency_tier)?;
 
     task.current_claims = task
         .current_claims
         .checked_add(1)
         .ok_or(ErrorCode::MathOverflow)?;
+    agent_account.last_active_at = now;
+
+    let clips = match emission_policy {
+        Some(policy) => policy.allocate(reward)?,
+        None => reward,
+    };
 
+    if task.verification_mode == VERIFICATION_OPTIMISTIC
+        || task.verification_mode == VERIFICATION_VALIDATOR
+    {
+        task.unsettled_claims = task
+            .unsettled_claims
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    match task.verification_mode {
+        VERIFICATION_OPTIMISTIC => {
+            let finalizes_at = now
+                .checked_add(i64::from(task.challenge_window_seconds))
+                .ok_or(ErrorCode::MathOverflow)?;
+            return Ok(Settlement {
+                clips,
+                boost_bps,
+                status: CLAIM_STATUS_PENDING,
+                finalizes_at,
+            });
+        }
+        VERIFICATION_VALIDATOR => {
+            return Ok(Settlement {
+                clips,
+                boost_bps,
+                status: CLAIM_STATUS_AWAITING_VALIDATOR,
+                finalizes_at: 0,
+            });
+        }
+        _ => {}
+    }
+
+    credit_clips(protocol, agent_account, clips)?;
+    Ok(Settlement {
+        clips,
+        boost_bps,
+        status: CLAIM_STATUS_APPROVED,
+        finalizes_at: now,
+    })
+}
+
+/// Pays out an approved claim to the agent and the protocol-wide tally.
+pub(crate) fn credit_clips(
+    protocol: &mut ProtocolState,
+    agent_account: &mut AgentAccount,
+    clips: u64,
+) -> Result<()> {
     agent_account.clips_balance = agent_account
         .clips_balance
-        .checked_add(task.reward_clips)
+        .checked_add(clips)
         .ok_or(ErrorCode::MathOverflow)?;
     agent_account.tasks_completed = agent_account
         .tasks_completed
         .checked_add(1)
         .ok_or(ErrorCode::MathOverflow)?;
-    agent_account.last_active_at = now;
 
     protocol.total_clips_distributed = protocol
         .total_clips_distributed
-        .checked_add(task.reward_clips)
+        .checked_add(clips)
         .ok_or(ErrorCode::MathOverflow)?;
 
-    let claim = &mut ctx.accounts.claim;
-    claim.bump = ctx.bumps.claim;
-    claim.layout_version = ACCOUNT_LAYOUT_V1;
-    claim.task_id = task_id;
-    claim.agent = ctx.accounts.agent.key();
-    claim.proof_cid = proof_cid;
-    claim.clips_awarded = task.reward_clips;
-    claim.completed_at = now;
-    claim.reserved = [0; CLAIM_RESERVED_BYTES];
-
     Ok(())
 }
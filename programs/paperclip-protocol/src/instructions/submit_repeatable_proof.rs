@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, AGENT_SEED, CLAIM_RESERVED_BYTES, CLAIM_SEED, ECONOMY_CONFIG_SEED,
+        EMISSION_POLICY_SEED, PROGRESS_RESERVED_BYTES, PROGRESS_SEED, PROTOCOL_SEED, TASK_SEED,
+    },
+    error::ErrorCode,
+    instructions::submit_proof::{settle_submission, validate_submission},
+    state::{
+        AgentAccount, ClaimRecord, EconomyConfig, EmissionPolicy, ProtocolState, RewardEvent,
+        TaskProgress, TaskRecord,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32, completion_index: u16)]
+pub struct SubmitRepeatableProof<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [EMISSION_POLICY_SEED],
+        bump = emission_policy.bump
+    )]
+    pub emission_policy: Option<Account<'info, EmissionPolicy>>,
+    pub reward_event: Option<Account<'info, RewardEvent>>,
+    #[account(
+        seeds = [ECONOMY_CONFIG_SEED],
+        bump = economy_config.bump
+    )]
+    pub economy_config: Account<'info, EconomyConfig>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent.key().as_ref()],
+        bump = agent_account.bump
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+    #[account(
+        init_if_needed,
+        payer = agent,
+        space = TaskProgress::SPACE,
+        seeds = [PROGRESS_SEED, task_id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump
+    )]
+    pub progress: Account<'info, TaskProgress>,
+    #[account(
+        init,
+        payer = agent,
+        space = ClaimRecord::SPACE,
+        seeds = [
+            CLAIM_SEED,
+            task_id.to_le_bytes().as_ref(),
+            agent.key().as_ref(),
+            completion_index.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, ClaimRecord>,
+    #[account(mut)]
+    pub agent: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SubmitRepeatableProof>,
+    task_id: u32,
+    completion_index: u16,
+    proof_cid: [u8; 64],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        ctx.accounts.task.is_repeatable,
+        ErrorCode::TaskNotRepeatable
+    );
+    validate_submission(
+        &ctx.accounts.protocol,
+        &ctx.accounts.task,
+        &ctx.accounts.agent_account,
+        &ctx.accounts.agent.key(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        now,
+    )?;
+
+    {
+        let task = &ctx.accounts.task;
+        let progress = &mut ctx.accounts.progress;
+
+        if progress.layout_version == 0 {
+            progress.bump = ctx.bumps.progress;
+            progress.layout_version = ACCOUNT_LAYOUT_V1;
+            progress.task_id = task_id;
+            progress.agent = ctx.accounts.agent.key();
+            progress.completions = 0;
+            progress.last_claim_at = 0;
+            progress.reserved = [0; PROGRESS_RESERVED_BYTES];
+        }
+
+        require!(
+            completion_index == progress.completions,
+            ErrorCode::InvalidCompletionIndex
+        );
+        require!(
+            task.max_completions == 0 || progress.completions < task.max_completions,
+            ErrorCode::MaxCompletionsReached
+        );
+        if progress.completions > 0 {
+            let next_claim_at = progress
+                .last_claim_at
+                .checked_add(i64::from(task.cooldown_seconds))
+                .ok_or(ErrorCode::MathOverflow)?;
+            require!(now >= next_claim_at, ErrorCode::TaskCooldownActive);
+        }
+
+        progress.completions = progress
+            .completions
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        progress.last_claim_at = now;
+    }
+
+    let emission_policy = ctx
+        .accounts
+        .protocol
+        .emission_policy(ctx.accounts.emission_policy.as_deref_mut())?;
+    let settlement = settle_submission(
+        &mut ctx.accounts.protocol,
+        &mut ctx.accounts.task,
+        &mut ctx.accounts.agent_account,
+        emission_policy,
+        ctx.accounts.reward_event.as_deref(),
+        &ctx.accounts.economy_config,
+        now,
+    )?;
+
+    let claim = &mut ctx.accounts.claim;
+    claim.bump = ctx.bumps.claim;
+    claim.layout_version = ACCOUNT_LAYOUT_V1;
+    claim.task_id = task_id;
+    claim.agent = ctx.accounts.agent.key();
+    claim.proof_cid = proof_cid;
+    claim.clips_awarded = settlement.clips;
+    claim.completed_at = now;
+    claim.completion_index = completion_index;
+    claim.status = settlement.status;
+    claim.finalizes_at = settlement.finalizes_at;
+    claim.flag_count = 0;
+    claim.payout_frozen = false;
+    claim.reward_boost_bps = settlement.boost_bps;
+    claim.clips_clawed_back = 0;
+    claim.reserved = [0; CLAIM_RESERVED_BYTES];
+
+    Ok(())
+}
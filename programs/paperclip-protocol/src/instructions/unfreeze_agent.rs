@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AGENT_SEED, PROTOCOL_SEED},
+    error::ErrorCode,
+    state::{AgentAccount, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct UnfreezeAgent<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, agent_account.wallet.as_ref()],
+        bump = agent_account.bump
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnfreezeAgent>) -> Result<()> {
+    ctx.accounts.agent_account.frozen_until = 0;
+    Ok(())
+}
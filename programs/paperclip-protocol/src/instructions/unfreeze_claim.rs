@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PROTOCOL_SEED,
+    error::ErrorCode,
+    state::{ClaimRecord, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct UnfreezeClaim<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(mut)]
+    pub claim: Account<'info, ClaimRecord>,
+    pub authority: Signer<'info>,
+}
+
+/// Clears a flag-triggered payout freeze after review. The flag count is kept.
+pub fn handler(ctx: Context<UnfreezeClaim>) -> Result<()> {
+    ctx.accounts.claim.payout_frozen = false;
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::PROTOCOL_SEED, error::ErrorCode, state::ProtocolState};
+
+#[derive(Accounts)]
+pub struct UnpauseProtocol<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnpauseProtocol>) -> Result<()> {
+    ctx.accounts.protocol.paused = false;
+    Ok(())
+}
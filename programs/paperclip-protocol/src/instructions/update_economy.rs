@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ACCOUNT_LAYOUT_V1, DEFAULT_TIER_REWARD_MULTIPLIER_BPS, ECONOMY_CONFIG_RESERVED_BYTES,
+        ECONOMY_CONFIG_SEED, PROTOCOL_SEED, TIER_COUNT,
+    },
+    error::ErrorCode,
+    state::{EconomyConfig, ProtocolState},
+};
+
+#[derive(Accounts)]
+pub struct UpdateEconomy<'info> {
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    /// Created here for deployments initialized before `EconomyConfig` existed.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = EconomyConfig::SPACE,
+        seeds = [ECONOMY_CONFIG_SEED],
+        bump
+    )]
+    pub economy_config: Account<'info, EconomyConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the reward parameters. Rewards already paid are not touched.
+pub fn handler(
+    ctx: Context<UpdateEconomy>,
+    base_reward_unit: u64,
+    registration_airdrop_bps: u16,
+    invitee_reward_bps: u16,
+    inviter_reward_bps: u16,
+) -> Result<()> {
+    ctx.accounts.protocol.base_reward_unit = base_reward_unit;
+
+    let economy_config = &mut ctx.accounts.economy_config;
+    if economy_config.layout_version == 0 {
+        economy_config.bump = ctx.bumps.economy_config;
+        economy_config.layout_version = ACCOUNT_LAYOUT_V1;
+        economy_config.tier_reward_multiplier_bps =
+            [DEFAULT_TIER_REWARD_MULTIPLIER_BPS; TIER_COUNT];
+        economy_config.reserved = [0; ECONOMY_CONFIG_RESERVED_BYTES];
+    }
+    economy_config.registration_airdrop_bps = registration_airdrop_bps;
+    economy_config.invitee_reward_bps = invitee_reward_bps;
+    economy_config.inviter_reward_bps = inviter_reward_bps;
+
+    Ok(())
+}
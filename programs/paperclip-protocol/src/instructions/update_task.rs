@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PROTOCOL_SEED, TASK_SEED},
+    error::ErrorCode,
+    state::{ProtocolState, TaskRecord},
+};
+
+#[derive(Accounts)]
+#[instruction(task_id: u32)]
+pub struct UpdateTask<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol.bump,
+        constraint = protocol.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol: Account<'info, ProtocolState>,
+    #[account(
+        mut,
+        seeds = [TASK_SEED, task_id.to_le_bytes().as_ref()],
+        bump = task.bump
+    )]
+    pub task: Account<'info, TaskRecord>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateTask>,
+    _task_id: u32,
+    title: [u8; 32],
+    content_cid: [u8; 64],
+    reward_clips: u64,
+    max_claims: u16,
+    min_tier: u8,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+
+    require!(
+        max_claims >= task.current_claims,
+        ErrorCode::MaxClaimsBelowCurrentClaims
+    );
+
+    task.title = title;
+    task.content_cid = content_cid;
+    task.reward_clips = reward_clips;
+    task.max_claims = max_claims;
+    task.min_tier = min_tier;
+    task.edit_count = task
+        .edit_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    task.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -56,6 +56,33 @@ pub mod paperclip_protocol {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_timed_task(
+        ctx: Context<CreateTask>,
+        task_id: u32,
+        title: [u8; 32],
+        content_cid: [u8; 64],
+        reward_clips: u64,
+        max_claims: u16,
+        min_tier: u8,
+        required_task_id: u32,
+        starts_at: i64,
+        ends_at: i64,
+    ) -> Result<()> {
+        create_timed_task::handler(
+            ctx,
+            task_id,
+            title,
+            content_cid,
+            reward_clips,
+            max_claims,
+            min_tier,
+            required_task_id,
+            starts_at,
+            ends_at,
+        )
+    }
+
     pub fn submit_proof(
         ctx: Context<SubmitProof>,
         task_id: u32,
@@ -64,7 +91,304 @@ pub mod paperclip_protocol {
         submit_proof::handler(ctx, task_id, proof_cid)
     }
 
+    pub fn submit_repeatable_proof(
+        ctx: Context<SubmitRepeatableProof>,
+        task_id: u32,
+        completion_index: u16,
+        proof_cid: [u8; 64],
+    ) -> Result<()> {
+        submit_repeatable_proof::handler(ctx, task_id, completion_index, proof_cid)
+    }
+
+    pub fn set_task_repeatable(
+        ctx: Context<SetTaskRepeatable>,
+        task_id: u32,
+        cooldown_seconds: u32,
+        max_completions: u16,
+    ) -> Result<()> {
+        set_task_repeatable::handler(ctx, task_id, cooldown_seconds, max_completions)
+    }
+
+    pub fn set_task_prerequisites(
+        ctx: Context<SetTaskPrerequisites>,
+        task_id: u32,
+        mode: u8,
+        task_ids: Vec<u32>,
+    ) -> Result<()> {
+        set_task_prerequisites::handler(ctx, task_id, mode, task_ids)
+    }
+
+    pub fn set_task_verification(
+        ctx: Context<SetTaskVerification>,
+        task_id: u32,
+        verification_mode: u8,
+        challenge_window_seconds: u32,
+    ) -> Result<()> {
+        set_task_verification::handler(ctx, task_id, verification_mode, challenge_window_seconds)
+    }
+
+    pub fn set_task_reward_curve(
+        ctx: Context<SetTaskRewardCurve>,
+        task_id: u32,
+        reward_curve: u8,
+        reward_curve_basis: u8,
+        reward_floor_bps: u16,
+        reward_curve_period: u32,
+    ) -> Result<()> {
+        set_task_reward_curve::handler(
+            ctx,
+            task_id,
+            reward_curve,
+            reward_curve_basis,
+            reward_floor_bps,
+            reward_curve_period,
+        )
+    }
+
+    pub fn set_task_reward_mode(
+        ctx: Context<SetTaskRewardMode>,
+        task_id: u32,
+        reward_mode: u8,
+        reward_base_unit_bps: u32,
+    ) -> Result<()> {
+        set_task_reward_mode::handler(ctx, task_id, reward_mode, reward_base_unit_bps)
+    }
+
+    pub fn set_task_validator(
+        ctx: Context<SetTaskValidator>,
+        task_id: u32,
+        validator: Pubkey,
+    ) -> Result<()> {
+        set_task_validator::handler(ctx, task_id, validator)
+    }
+
+    pub fn approve_claim(ctx: Context<ApproveClaim>) -> Result<()> {
+        approve_claim::handler(ctx)
+    }
+
+    pub fn finalize_claim(ctx: Context<FinalizeClaim>) -> Result<()> {
+        finalize_claim::handler(ctx)
+    }
+
+    pub fn reject_claim(ctx: Context<RejectClaim>) -> Result<()> {
+        reject_claim::handler(ctx)
+    }
+
     pub fn deactivate_task(ctx: Context<DeactivateTask>, task_id: u32) -> Result<()> {
         deactivate_task::handler(ctx, task_id)
     }
+
+    pub fn reactivate_task(ctx: Context<ReactivateTask>, task_id: u32) -> Result<()> {
+        reactivate_task::handler(ctx, task_id)
+    }
+
+    pub fn close_task(ctx: Context<CloseTask>, task_id: u32) -> Result<()> {
+        close_task::handler(ctx, task_id)
+    }
+
+    pub fn pause_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
+        pause_protocol::handler(ctx)
+    }
+
+    pub fn unpause_protocol(ctx: Context<UnpauseProtocol>) -> Result<()> {
+        unpause_protocol::handler(ctx)
+    }
+
+    pub fn set_feature_flags(ctx: Context<SetFeatureFlags>, feature_flags: u32) -> Result<()> {
+        set_feature_flags::handler(ctx, feature_flags)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    pub fn grant_publisher_role(
+        ctx: Context<GrantPublisherRole>,
+        wallet: Pubkey,
+        permissions: u8,
+        min_task_id: u32,
+        max_task_id: u32,
+    ) -> Result<()> {
+        grant_publisher_role::handler(ctx, wallet, permissions, min_task_id, max_task_id)
+    }
+
+    pub fn revoke_publisher_role(ctx: Context<RevokePublisherRole>, wallet: Pubkey) -> Result<()> {
+        revoke_publisher_role::handler(ctx, wallet)
+    }
+
+    pub fn update_task(
+        ctx: Context<UpdateTask>,
+        task_id: u32,
+        title: [u8; 32],
+        content_cid: [u8; 64],
+        reward_clips: u64,
+        max_claims: u16,
+        min_tier: u8,
+    ) -> Result<()> {
+        update_task::handler(
+            ctx,
+            task_id,
+            title,
+            content_cid,
+            reward_clips,
+            max_claims,
+            min_tier,
+        )
+    }
+
+    pub fn set_dispute_config(
+        ctx: Context<SetDisputeConfig>,
+        min_challenger_tier: u8,
+        min_challenge_stake: u64,
+        challenger_reward_bps: u16,
+        forfeit_stake_to_claimant: bool,
+        flag_freeze_threshold: u16,
+    ) -> Result<()> {
+        set_dispute_config::handler(
+            ctx,
+            min_challenger_tier,
+            min_challenge_stake,
+            challenger_reward_bps,
+            forfeit_stake_to_claimant,
+            flag_freeze_threshold,
+        )
+    }
+
+    pub fn challenge_claim(
+        ctx: Context<ChallengeClaim>,
+        stake: u64,
+        evidence_cid: [u8; 64],
+    ) -> Result<()> {
+        challenge_claim::handler(ctx, stake, evidence_cid)
+    }
+
+    pub fn resolve_challenge(ctx: Context<ResolveChallenge>, uphold: bool) -> Result<()> {
+        resolve_challenge::handler(ctx, uphold)
+    }
+
+    pub fn flag_claim(
+        ctx: Context<FlagClaim>,
+        reason_code: u8,
+        evidence_cid: [u8; 64],
+    ) -> Result<()> {
+        flag_claim::handler(ctx, reason_code, evidence_cid)
+    }
+
+    pub fn unfreeze_claim(ctx: Context<UnfreezeClaim>) -> Result<()> {
+        unfreeze_claim::handler(ctx)
+    }
+
+    pub fn slash_agent(
+        ctx: Context<SlashAgent>,
+        amount: u64,
+        reason_code: u8,
+        evidence_cid: [u8; 64],
+    ) -> Result<()> {
+        slash_agent::handler(ctx, amount, reason_code, evidence_cid)
+    }
+
+    pub fn freeze_agent(ctx: Context<FreezeAgent>, frozen_until: Option<i64>) -> Result<()> {
+        freeze_agent::handler(ctx, frozen_until)
+    }
+
+    pub fn unfreeze_agent(ctx: Context<UnfreezeAgent>) -> Result<()> {
+        unfreeze_agent::handler(ctx)
+    }
+
+    pub fn file_appeal(ctx: Context<FileAppeal>, appeal_cid: [u8; 64]) -> Result<()> {
+        file_appeal::handler(ctx, appeal_cid)
+    }
+
+    pub fn resolve_appeal(ctx: Context<ResolveAppeal>, uphold: bool) -> Result<()> {
+        resolve_appeal::handler(ctx, uphold)
+    }
+
+    pub fn set_tier_config(
+        ctx: Context<SetTierConfig>,
+        min_clips_balance: [u64; TIER_COUNT],
+        min_tasks_completed: [u32; TIER_COUNT],
+        promotion_cooldown_seconds: u32,
+        decay_after_seconds: [u32; TIER_COUNT],
+    ) -> Result<()> {
+        set_tier_config::handler(
+            ctx,
+            min_clips_balance,
+            min_tasks_completed,
+            promotion_cooldown_seconds,
+            decay_after_seconds,
+        )
+    }
+
+    pub fn promote_agent(ctx: Context<PromoteAgent>) -> Result<()> {
+        promote_agent::handler(ctx)
+    }
+
+    pub fn decay_tier(ctx: Context<DecayTier>) -> Result<()> {
+        decay_tier::handler(ctx)
+    }
+
+    pub fn update_economy(
+        ctx: Context<UpdateEconomy>,
+        base_reward_unit: u64,
+        registration_airdrop_bps: u16,
+        invitee_reward_bps: u16,
+        inviter_reward_bps: u16,
+    ) -> Result<()> {
+        update_economy::handler(
+            ctx,
+            base_reward_unit,
+            registration_airdrop_bps,
+            invitee_reward_bps,
+            inviter_reward_bps,
+        )
+    }
+
+    pub fn set_emission_policy(
+        ctx: Context<SetEmissionPolicy>,
+        supply_cap: u64,
+        epoch_budget: u64,
+        epoch_duration_seconds: u32,
+        halving_interval_epochs: u32,
+        pro_rate: bool,
+    ) -> Result<()> {
+        set_emission_policy::handler(
+            ctx,
+            supply_cap,
+            epoch_budget,
+            epoch_duration_seconds,
+            halving_interval_epochs,
+            pro_rate,
+        )
+    }
+
+    pub fn set_reward_event(
+        ctx: Context<SetRewardEvent>,
+        event_id: u32,
+        starts_at: i64,
+        ends_at: i64,
+        multiplier_bps: u32,
+        min_task_id: u32,
+        max_task_id: u32,
+    ) -> Result<()> {
+        set_reward_event::handler(
+            ctx,
+            event_id,
+            starts_at,
+            ends_at,
+            multiplier_bps,
+            min_task_id,
+            max_task_id,
+        )
+    }
+
+    pub fn set_tier_multipliers(
+        ctx: Context<SetTierMultipliers>,
+        tier_reward_multiplier_bps: [u16; TIER_COUNT],
+    ) -> Result<()> {
+        set_tier_multipliers::handler(ctx, tier_reward_multiplier_bps)
+    }
 }
@@ -1,8 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{
-    AGENT_RESERVED_BYTES, CLAIM_RESERVED_BYTES, INVITE_RESERVED_BYTES, PROTOCOL_RESERVED_BYTES,
-    TASK_RESERVED_BYTES,
+use crate::{
+    constants::{
+        AGENT_RESERVED_BYTES, APPEAL_RESERVED_BYTES, BPS_DENOMINATOR, CHALLENGE_RESERVED_BYTES,
+        CLAIM_RESERVED_BYTES, DISPUTE_CONFIG_RESERVED_BYTES, ECONOMY_CONFIG_RESERVED_BYTES,
+        EMISSION_POLICY_RESERVED_BYTES, FLAG_RESERVED_BYTES, INVITE_RESERVED_BYTES,
+        MAX_PREREQUISITES, PREREQ_RESERVED_BYTES, PROGRESS_RESERVED_BYTES, PROTOCOL_RESERVED_BYTES,
+        PUBLISHER_RESERVED_BYTES, REJECTION_RESERVED_BYTES, REWARD_CURVE_BY_TIME,
+        REWARD_CURVE_LINEAR, REWARD_CURVE_NONE, REWARD_EVENT_RESERVED_BYTES,
+        REWARD_MODE_BASE_UNITS, SLASH_RESERVED_BYTES, TASK_RESERVED_BYTES,
+        TASK_TOMBSTONE_RESERVED_BYTES, TIER_CONFIG_RESERVED_BYTES, TIER_COUNT,
+    },
+    error::ErrorCode,
 };
 
 #[account]
@@ -15,11 +24,41 @@ pub struct ProtocolState {
     pub total_tasks: u32,
     pub total_clips_distributed: u64,
     pub paused: bool,
+    pub feature_flags: u32,
+    pub pending_authority: Pubkey,
+    /// Cumulative Clips removed by `slash_agent`; circulating supply is
+    /// `total_clips_distributed - clips_slashed - clips_burned`.
+    pub clips_slashed: u64,
+    /// Set once `set_emission_policy` has run; from then on every reward path
+    /// must pass the `EmissionPolicy` account.
+    pub emission_policy_active: bool,
+    /// Cumulative Clips burned by challenge resolution: the clawback share the
+    /// challenger doesn't receive, and forfeited stakes that aren't paid out.
+    pub clips_burned: u64,
     pub reserved: [u8; PROTOCOL_RESERVED_BYTES],
 }
 
 impl ProtocolState {
-    pub const SPACE: usize = 8 + 1 + 1 + 32 + 8 + 4 + 4 + 8 + 1 + PROTOCOL_RESERVED_BYTES;
+    pub const SPACE: usize =
+        8 + 1 + 1 + 32 + 8 + 4 + 4 + 8 + 1 + 4 + 32 + 8 + 1 + 8 + PROTOCOL_RESERVED_BYTES;
+
+    pub fn is_feature_disabled(&self, flag: u32) -> bool {
+        self.feature_flags & flag != 0
+    }
+
+    /// Returns the emission policy a reward path must apply, rejecting calls
+    /// that omit it while one is active.
+    pub fn emission_policy<'a>(
+        &self,
+        emission_policy: Option<&'a mut EmissionPolicy>,
+    ) -> Result<Option<&'a mut EmissionPolicy>> {
+        if !self.emission_policy_active {
+            return Ok(None);
+        }
+        emission_policy
+            .map(Some)
+            .ok_or_else(|| error!(ErrorCode::EmissionPolicyRequired))
+    }
 }
 
 #[account]
@@ -35,12 +74,26 @@ pub struct AgentAccount {
     pub invites_sent: u32,
     pub invites_redeemed: u32,
     pub invited_by: Pubkey,
+    pub clips_slashed: u64,
+    /// Number of `SlashRecord`s written for this agent; seeds the next one.
+    pub slash_count: u32,
+    /// Unix timestamp until which the agent is frozen; 0 when not frozen.
+    pub frozen_until: i64,
+    pub last_promoted_at: i64,
+    pub last_decayed_at: i64,
+    /// Number of `RejectionRecord`s written for this agent; seeds the next one.
+    pub rejection_count: u32,
     pub reserved: [u8; AGENT_RESERVED_BYTES],
 }
 
 impl AgentAccount {
     pub const SPACE: usize =
-        8 + 1 + 1 + 32 + 8 + 1 + 4 + 8 + 8 + 4 + 4 + 32 + AGENT_RESERVED_BYTES;
+        8 + 1 + 1 + 32 + 8 + 1 + 4 + 8 + 8 + 4 + 4 + 32 + 8 + 4 + 8 + 8 + 8 + 4
+            + AGENT_RESERVED_BYTES;
+
+    pub fn is_frozen(&self, now: i64) -> bool {
+        now < self.frozen_until
+    }
 }
 
 #[account]
@@ -58,12 +111,102 @@ pub struct TaskRecord {
     pub created_at: i64,
     pub min_tier: u8,
     pub required_task_id: u32,
+    pub edit_count: u16,
+    pub updated_at: i64,
+    /// Unix timestamp before which proofs are rejected; 0 means no start bound.
+    pub starts_at: i64,
+    /// Unix timestamp from which proofs are rejected; 0 means no end bound.
+    pub ends_at: i64,
+    /// Repeatable tasks are claimed through `submit_repeatable_proof`, once per
+    /// completion index, instead of once per agent.
+    pub is_repeatable: bool,
+    pub cooldown_seconds: u32,
+    /// Per-agent completion cap for repeatable tasks; 0 means unlimited.
+    pub max_completions: u16,
+    /// Set when a `TaskPrerequisites` extension account gates this task.
+    pub has_prerequisite_set: bool,
+    pub verification_mode: u8,
+    /// How long optimistic claims stay pending before `finalize_claim` pays out.
+    pub challenge_window_seconds: u32,
+    /// Signs `approve_claim`/`reject_claim` for tasks in validator mode.
+    pub validator: Pubkey,
+    pub reward_mode: u8,
+    /// Reward as basis points of `ProtocolState.base_reward_unit`, read when
+    /// `reward_mode` is `REWARD_MODE_BASE_UNITS`.
+    pub reward_base_unit_bps: u32,
+    pub reward_curve: u8,
+    /// Whether the curve advances per claim or per second since `created_at`.
+    pub reward_curve_basis: u8,
+    /// Lowest payout the curve decays to, as basis points of the starting reward.
+    pub reward_floor_bps: u16,
+    /// Claims or seconds for a linear curve to reach the floor, or for an
+    /// exponential curve to halve the distance to it. The exponential curve
+    /// halves in steps, once per full period, rather than decaying smoothly.
+    pub reward_curve_period: u32,
+    /// Claims still pending or awaiting the validator. The task can't be closed
+    /// or leave validator mode until they settle.
+    pub unsettled_claims: u16,
     pub reserved: [u8; TASK_RESERVED_BYTES],
 }
 
 impl TaskRecord {
     pub const SPACE: usize =
-        8 + 1 + 1 + 4 + 32 + 32 + 64 + 8 + 2 + 2 + 1 + 8 + 1 + 4 + TASK_RESERVED_BYTES;
+        8 + 1 + 1 + 4 + 32 + 32 + 64 + 8 + 2 + 2 + 1 + 8 + 1 + 4 + 2 + 8 + 8 + 8 + 1 + 4 + 2
+        + 1
+        + 1
+        + 4
+        + 32
+        + 1
+        + 4
+        + 1
+        + 1
+        + 2
+        + 4
+        + 2
+        + TASK_RESERVED_BYTES;
+
+    /// Clips a proof submitted now earns, before emission limits. Must be read
+    /// before the submission is counted in `current_claims`, so the first
+    /// claimant earns the full starting reward.
+    pub fn resolve_reward(&self, base_reward_unit: u64, now: i64) -> Result<u64> {
+        let start = match self.reward_mode {
+            REWARD_MODE_BASE_UNITS => scale_bps(base_reward_unit, self.reward_base_unit_bps)?,
+            _ => self.reward_clips,
+        };
+        if self.reward_curve == REWARD_CURVE_NONE || self.reward_curve_period == 0 {
+            return Ok(start);
+        }
+
+        let elapsed = match self.reward_curve_basis {
+            REWARD_CURVE_BY_TIME => u64::try_from(now.saturating_sub(self.created_at)).unwrap_or(0),
+            _ => u64::from(self.current_claims),
+        };
+        let period = u64::from(self.reward_curve_period);
+        let floor = scale_bps(start, u32::from(self.reward_floor_bps))?;
+        let span = start.saturating_sub(floor);
+
+        let remaining = match self.reward_curve {
+            REWARD_CURVE_LINEAR => {
+                let left = period.saturating_sub(elapsed);
+                let scaled = u128::from(span) * u128::from(left) / u128::from(period);
+                u64::try_from(scaled).map_err(|_| error!(ErrorCode::MathOverflow))?
+            }
+            _ => u32::try_from(elapsed / period)
+                .ok()
+                .and_then(|halvings| span.checked_shr(halvings))
+                .unwrap_or(0),
+        };
+
+        Ok(floor
+            .checked_add(remaining)
+            .ok_or(ErrorCode::MathOverflow)?)
+    }
+
+    /// Drops a settled claim from `unsettled_claims`. Saturates for claims
+    /// submitted before the counter existed.
+    pub fn settle_claim(&mut self) {
+        self.unsettled_claims = self.unsettled_claims.saturating_sub(1);
+    }
 }
 
 #[account]
@@ -75,11 +218,26 @@ pub struct ClaimRecord {
     pub proof_cid: [u8; 64],
     pub clips_awarded: u64,
     pub completed_at: i64,
+    pub completion_index: u16,
+    pub status: u8,
+    /// When a pending claim can be finalized; submission time for instant claims
+    /// and 0 for claims awaiting a validator.
+    pub finalizes_at: i64,
+    pub flag_count: u16,
+    /// Set once `flag_count` reaches the dispute threshold; blocks finalizing or
+    /// approving a pending payout until the authority unfreezes the claim.
+    pub payout_frozen: bool,
+    /// Multiplier a `RewardEvent` applied to `clips_awarded`; 0 when none did.
+    pub reward_boost_bps: u32,
+    /// What an upheld challenge took back from the claimant; an upheld appeal
+    /// restores this much.
+    pub clips_clawed_back: u64,
     pub reserved: [u8; CLAIM_RESERVED_BYTES],
 }
 
 impl ClaimRecord {
-    pub const SPACE: usize = 8 + 1 + 1 + 4 + 32 + 64 + 8 + 8 + CLAIM_RESERVED_BYTES;
+    pub const SPACE: usize =
+        8 + 1 + 1 + 4 + 32 + 64 + 8 + 8 + 2 + 1 + 8 + 2 + 1 + 4 + 8 + CLAIM_RESERVED_BYTES;
 }
 
 #[account]
@@ -98,3 +256,427 @@ impl InviteRecord {
     pub const SPACE: usize =
         8 + 1 + 1 + 32 + 32 + 4 + 8 + 1 + INVITE_RESERVED_BYTES;
 }
+
+#[account]
+pub struct PublisherRole {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub wallet: Pubkey,
+    pub permissions: u8,
+    pub min_task_id: u32,
+    pub max_task_id: u32,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub reserved: [u8; PUBLISHER_RESERVED_BYTES],
+}
+
+impl PublisherRole {
+    pub const SPACE: usize = 8 + 1 + 1 + 32 + 1 + 4 + 4 + 32 + 8 + PUBLISHER_RESERVED_BYTES;
+
+    /// Passes when `signer` is the protocol authority, or holds a publisher role
+    /// with `permission` whose task-id range covers `task_id`.
+    pub fn authorize(
+        protocol: &ProtocolState,
+        signer: &Pubkey,
+        role: Option<&PublisherRole>,
+        permission: u8,
+        task_id: u32,
+    ) -> Result<()> {
+        if protocol.authority == *signer {
+            return Ok(());
+        }
+
+        let role = role.ok_or(ErrorCode::Unauthorized)?;
+        require_keys_eq!(role.wallet, *signer, ErrorCode::Unauthorized);
+        require!(role.permissions & permission != 0, ErrorCode::Unauthorized);
+        require!(
+            task_id >= role.min_task_id && task_id <= role.max_task_id,
+            ErrorCode::TaskIdOutsidePublisherRange
+        );
+        Ok(())
+    }
+}
+
+/// Per-agent, per-task tracker for repeatable tasks.
+#[account]
+pub struct TaskProgress {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub task_id: u32,
+    pub agent: Pubkey,
+    pub completions: u16,
+    pub last_claim_at: i64,
+    pub reserved: [u8; PROGRESS_RESERVED_BYTES],
+}
+
+impl TaskProgress {
+    pub const SPACE: usize = 8 + 1 + 1 + 4 + 32 + 2 + 8 + PROGRESS_RESERVED_BYTES;
+}
+
+/// Extension of `TaskRecord` listing several prerequisite tasks, satisfied when
+/// all (`PREREQ_MODE_ALL`) or any (`PREREQ_MODE_ANY`) of them were claimed.
+/// It is checked in addition to `TaskRecord.required_task_id`.
+#[account]
+pub struct TaskPrerequisites {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub task_id: u32,
+    pub mode: u8,
+    pub count: u8,
+    pub task_ids: [u32; MAX_PREREQUISITES],
+    pub reserved: [u8; PREREQ_RESERVED_BYTES],
+}
+
+impl TaskPrerequisites {
+    pub const SPACE: usize = 8 + 1 + 1 + 4 + 1 + 1 + 4 * MAX_PREREQUISITES + PREREQ_RESERVED_BYTES;
+
+    pub fn required_task_ids(&self) -> &[u32] {
+        &self.task_ids[..usize::from(self.count).min(MAX_PREREQUISITES)]
+    }
+}
+
+/// Protocol-wide rules for staked claim challenges.
+#[account]
+pub struct DisputeConfig {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub min_challenger_tier: u8,
+    pub min_challenge_stake: u64,
+    /// Share of a clawed-back reward paid to a successful challenger; the rest
+    /// is burned.
+    pub challenger_reward_bps: u16,
+    /// Where a dismissed challenger's stake goes: the claimant, or burned.
+    pub forfeit_stake_to_claimant: bool,
+    /// Flags after which a claim's payout is frozen; 0 disables auto-freeze.
+    pub flag_freeze_threshold: u16,
+    pub reserved: [u8; DISPUTE_CONFIG_RESERVED_BYTES],
+}
+
+impl DisputeConfig {
+    pub const SPACE: usize = 8 + 1 + 1 + 1 + 8 + 2 + 1 + 2 + DISPUTE_CONFIG_RESERVED_BYTES;
+}
+
+#[account]
+pub struct ChallengeRecord {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub claim: Pubkey,
+    pub claimant: Pubkey,
+    pub challenger: Pubkey,
+    pub stake: u64,
+    pub evidence_cid: [u8; 64],
+    pub status: u8,
+    pub created_at: i64,
+    pub resolved_at: i64,
+    pub reserved: [u8; CHALLENGE_RESERVED_BYTES],
+}
+
+impl ChallengeRecord {
+    pub const SPACE: usize =
+        8 + 1 + 1 + 32 + 32 + 32 + 8 + 64 + 1 + 8 + 8 + CHALLENGE_RESERVED_BYTES;
+}
+
+#[account]
+pub struct FlagRecord {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub claim: Pubkey,
+    pub flagger: Pubkey,
+    /// Off-chain defined reason category, indexed by the Genesis dashboard.
+    pub reason_code: u8,
+    pub evidence_cid: [u8; 64],
+    pub created_at: i64,
+    pub reserved: [u8; FLAG_RESERVED_BYTES],
+}
+
+impl FlagRecord {
+    pub const SPACE: usize = 8 + 1 + 1 + 32 + 32 + 1 + 64 + 8 + FLAG_RESERVED_BYTES;
+}
+
+#[account]
+pub struct SlashRecord {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub agent: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+    pub reason_code: u8,
+    pub evidence_cid: [u8; 64],
+    pub slashed_by: Pubkey,
+    pub created_at: i64,
+    pub reserved: [u8; SLASH_RESERVED_BYTES],
+}
+
+impl SlashRecord {
+    pub const SPACE: usize = 8 + 1 + 1 + 32 + 4 + 8 + 1 + 64 + 32 + 8 + SLASH_RESERVED_BYTES;
+}
+
+/// Written by `reject_claim` when it closes a claim, so the agent keeps
+/// something to appeal.
+#[account]
+pub struct RejectionRecord {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub agent: Pubkey,
+    pub index: u32,
+    pub task_id: u32,
+    /// Whether the claim was seeded by `completion_index`; an upheld appeal
+    /// recreates it at the same address.
+    pub is_repeatable: bool,
+    pub completion_index: u16,
+    pub proof_cid: [u8; 64],
+    pub clips_awarded: u64,
+    pub reward_boost_bps: u32,
+    pub submitted_at: i64,
+    pub rejected_by: Pubkey,
+    pub created_at: i64,
+    pub reserved: [u8; REJECTION_RESERVED_BYTES],
+}
+
+impl RejectionRecord {
+    pub const SPACE: usize =
+        8 + 1 + 1 + 32 + 4 + 4 + 1 + 2 + 64 + 8 + 4 + 8 + 32 + 8 + REJECTION_RESERVED_BYTES;
+}
+
+#[account]
+pub struct AppealRecord {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub appellant: Pubkey,
+    /// The appealed `SlashRecord`, `ClaimRecord` or `RejectionRecord`, per
+    /// `subject_kind`.
+    pub subject: Pubkey,
+    pub subject_kind: u8,
+    pub appeal_cid: [u8; 64],
+    pub status: u8,
+    pub clips_restored: u64,
+    pub created_at: i64,
+    pub resolved_at: i64,
+    pub reserved: [u8; APPEAL_RESERVED_BYTES],
+}
+
+impl AppealRecord {
+    pub const SPACE: usize =
+        8 + 1 + 1 + 32 + 32 + 1 + 64 + 1 + 8 + 8 + 8 + APPEAL_RESERVED_BYTES;
+}
+
+#[account]
+pub struct TierConfig {
+    pub bump: u8,
+    pub layout_version: u8,
+    /// Thresholds to reach each tier, indexed by tier; index 0 (Intern) is unused.
+    pub min_clips_balance: [u64; TIER_COUNT],
+    pub min_tasks_completed: [u32; TIER_COUNT],
+    pub promotion_cooldown_seconds: u32,
+    /// Inactivity after which an agent at each tier drops one tier; 0 disables decay.
+    pub decay_after_seconds: [u32; TIER_COUNT],
+    pub reserved: [u8; TIER_CONFIG_RESERVED_BYTES],
+}
+
+impl TierConfig {
+    pub const SPACE: usize = 8
+        + 1
+        + 1
+        + 8 * TIER_COUNT
+        + 4 * TIER_COUNT
+        + 4
+        + 4 * TIER_COUNT
+        + TIER_CONFIG_RESERVED_BYTES;
+}
+
+/// Tunable reward parameters. The base unit itself stays in
+/// `ProtocolState.base_reward_unit`; `update_economy` changes both.
+#[account]
+pub struct EconomyConfig {
+    pub bump: u8,
+    pub layout_version: u8,
+    /// Clips credited by `register_agent`, in basis points of the base unit so
+    /// the airdrop halves along with task rewards.
+    pub registration_airdrop_bps: u16,
+    /// Invitee and inviter rewards from `register_agent_with_invite`, in basis
+    /// points of the base unit.
+    pub invitee_reward_bps: u16,
+    pub inviter_reward_bps: u16,
+    /// Task reward multiplier by `AgentAccount.efficiency_tier`, in basis points;
+    /// 0 (configs created before the table existed) pays the unscaled reward.
+    pub tier_reward_multiplier_bps: [u16; TIER_COUNT],
+    pub reserved: [u8; ECONOMY_CONFIG_RESERVED_BYTES],
+}
+
+impl EconomyConfig {
+    pub const SPACE: usize =
+        8 + 1 + 1 + 2 + 2 + 2 + 2 * TIER_COUNT + ECONOMY_CONFIG_RESERVED_BYTES;
+
+    pub fn tier_reward(&self, reward: u64, tier: u8) -> Result<u64> {
+        match self.tier_reward_multiplier_bps.get(usize::from(tier)) {
+            Some(&bps) if bps > 0 => scale_bps(reward, u32::from(bps)),
+            _ => Ok(reward),
+        }
+    }
+
+    pub fn registration_airdrop(&self, base_reward_unit: u64) -> Result<u64> {
+        scale_bps(base_reward_unit, u32::from(self.registration_airdrop_bps))
+    }
+
+    pub fn invitee_reward(&self, base_reward_unit: u64) -> Result<u64> {
+        scale_bps(base_reward_unit, u32::from(self.invitee_reward_bps))
+    }
+
+    pub fn inviter_reward(&self, base_reward_unit: u64) -> Result<u64> {
+        scale_bps(base_reward_unit, u32::from(self.inviter_reward_bps))
+    }
+}
+
+/// Caps Clips emission. Epochs are `epoch_duration_seconds` long, counted from
+/// `genesis_at`; a zero cap, budget or halving interval disables that limit.
+#[account]
+pub struct EmissionPolicy {
+    pub bump: u8,
+    pub layout_version: u8,
+    /// Ceiling on `total_allocated`.
+    pub supply_cap: u64,
+    pub epoch_budget: u64,
+    pub epoch_duration_seconds: u32,
+    /// `base_reward_unit` halves every this many epochs.
+    pub halving_interval_epochs: u32,
+    /// Pay out whatever is left instead of rejecting once a limit is reached.
+    pub pro_rate: bool,
+    pub genesis_at: i64,
+    pub current_epoch: u32,
+    pub epoch_emitted: u64,
+    pub halvings_applied: u32,
+    /// Clips booked since the policy was created, starting from the distributed
+    /// total at the time. Pending claims count from submission, so they can't
+    /// push credited Clips past the cap.
+    pub total_allocated: u64,
+    /// When the policy was created. Unlike `genesis_at`, it never moves, so it
+    /// tells claims booked against the policy from older ones.
+    pub activated_at: i64,
+    pub reserved: [u8; EMISSION_POLICY_RESERVED_BYTES],
+}
+
+impl EmissionPolicy {
+    pub const SPACE: usize =
+        8 + 1 + 1 + 8 + 8 + 4 + 4 + 1 + 8 + 4 + 8 + 4 + 8 + 8 + EMISSION_POLICY_RESERVED_BYTES;
+
+    /// Moves to the epoch containing `now`, resetting its budget, and halves the
+    /// base unit for every halving interval crossed since the last call.
+    pub fn advance(&mut self, protocol: &mut ProtocolState, now: i64) -> Result<()> {
+        let elapsed = u64::try_from(now.saturating_sub(self.genesis_at)).unwrap_or(0);
+        let epoch =
+            u32::try_from(elapsed / u64::from(self.epoch_duration_seconds)).unwrap_or(u32::MAX);
+        if epoch > self.current_epoch {
+            self.current_epoch = epoch;
+            self.epoch_emitted = 0;
+        }
+
+        if let Some(halvings_due) = self.current_epoch.checked_div(self.halving_interval_epochs) {
+            if halvings_due > self.halvings_applied {
+                protocol.base_reward_unit = protocol
+                    .base_reward_unit
+                    .checked_shr(halvings_due - self.halvings_applied)
+                    .unwrap_or(0);
+                self.halvings_applied = halvings_due;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Books up to `requested` Clips against the supply cap and the current
+    /// epoch's budget and returns the amount granted.
+    pub fn allocate(&mut self, requested: u64) -> Result<u64> {
+        let supply_left = match self.supply_cap {
+            0 => u64::MAX,
+            cap => cap.saturating_sub(self.total_allocated),
+        };
+        let epoch_left = match self.epoch_budget {
+            0 => u64::MAX,
+            budget => budget.saturating_sub(self.epoch_emitted),
+        };
+        let available = supply_left.min(epoch_left);
+
+        let granted = if requested <= available {
+            requested
+        } else {
+            require!(self.pro_rate, ErrorCode::EmissionExhausted);
+            available
+        };
+        self.epoch_emitted = self
+            .epoch_emitted
+            .checked_add(granted)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_allocated = self
+            .total_allocated
+            .checked_add(granted)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(granted)
+    }
+
+    /// Returns Clips booked at `booked_at` that will never be credited. Claims
+    /// booked before the policy existed were never counted; the epoch budget
+    /// only gets back what was booked in the current epoch.
+    pub fn release(&mut self, amount: u64, booked_at: i64) {
+        if booked_at < self.activated_at {
+            return;
+        }
+        self.total_allocated = self.total_allocated.saturating_sub(amount);
+
+        let epoch_start = i64::from(self.current_epoch)
+            .checked_mul(i64::from(self.epoch_duration_seconds))
+            .and_then(|offset| self.genesis_at.checked_add(offset))
+            .unwrap_or(i64::MAX);
+        if booked_at >= epoch_start {
+            self.epoch_emitted = self.epoch_emitted.saturating_sub(amount);
+        }
+    }
+}
+
+/// Time-boxed reward multiplier, e.g. a launch-weekend "double Clips" event.
+/// Proofs submitted with the event account earn `multiplier_bps` of the task
+/// reward if they fall inside the window and the task-id range.
+#[account]
+pub struct RewardEvent {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub event_id: u32,
+    pub starts_at: i64,
+    /// Proofs are boosted up to, but not including, this timestamp.
+    pub ends_at: i64,
+    pub multiplier_bps: u32,
+    /// Inclusive task-id range; `0..=u32::MAX` matches every task.
+    pub min_task_id: u32,
+    pub max_task_id: u32,
+    pub reserved: [u8; REWARD_EVENT_RESERVED_BYTES],
+}
+
+impl RewardEvent {
+    pub const SPACE: usize = 8 + 1 + 1 + 4 + 8 + 8 + 4 + 4 + 4 + REWARD_EVENT_RESERVED_BYTES;
+
+    pub fn applies_to(&self, task_id: u32, now: i64) -> bool {
+        now >= self.starts_at
+            && now < self.ends_at
+            && (self.min_task_id..=self.max_task_id).contains(&task_id)
+    }
+}
+
+/// Left behind by `close_task` so the closed task's id is never handed out
+/// again; claims and prerequisite proofs still reference it.
+#[account]
+pub struct TaskTombstone {
+    pub bump: u8,
+    pub layout_version: u8,
+    pub task_id: u32,
+    pub closed_at: i64,
+    pub reserved: [u8; TASK_TOMBSTONE_RESERVED_BYTES],
+}
+
+impl TaskTombstone {
+    pub const SPACE: usize = 8 + 1 + 1 + 4 + 8 + TASK_TOMBSTONE_RESERVED_BYTES;
+}
+
+pub(crate) fn scale_bps(amount: u64, bps: u32) -> Result<u64> {
+    let scaled = u128::from(amount) * u128::from(bps) / u128::from(BPS_DENOMINATOR);
+    u64::try_from(scaled).map_err(|_| error!(ErrorCode::MathOverflow))
+}
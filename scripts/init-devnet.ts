@@ -29,6 +29,7 @@ const DEFAULT_WALLET_PATH = path.join(os.homedir(), ".config", "solana", "id.jso
 
 const PROTOCOL_SEED = Buffer.from("protocol");
 const AGENT_SEED = Buffer.from("agent");
+const ECONOMY_CONFIG_SEED = Buffer.from("economy_config");
 
 interface SeedOptions {
   enabled: boolean;
@@ -199,6 +200,10 @@ async function main() {
     [PROTOCOL_SEED],
     program.programId
   );
+  const [economyConfigPda] = PublicKey.findProgramAddressSync(
+    [ECONOMY_CONFIG_SEED],
+    program.programId
+  );
 
   // 2. Initialize protocol
   console.log("\n[2/3] Initializing protocol...");
@@ -207,6 +212,7 @@ async function main() {
       .initialize(new BN(100))
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         authority: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -239,6 +245,7 @@ async function main() {
       .registerAgent()
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         agentAccount: agentPda,
         agent: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -52,6 +52,7 @@ const CATALOG_PATH = path.resolve(ROOT_DIR, "tasks", "catalog.json");
 const PROTOCOL_SEED = Buffer.from("protocol");
 const TASK_SEED = Buffer.from("task");
 const NO_PREREQ_TASK_ID = 0xffffffff;
+const MAX_PREREQ_CHAIN_DEPTH = 16;
 
 interface PublishOptions {
   dryRun: boolean;
@@ -322,6 +323,27 @@ function getTaskPda(programId: PublicKey, taskId: number): PublicKey {
   return PublicKey.findProgramAddressSync([TASK_SEED, taskIdBytes(taskId)], programId)[0];
 }
 
+// create_task checks the prerequisite chain for cycles and needs one TaskRecord
+// per link, ending at a task without a prerequisite or one that no longer exists.
+async function getPrerequisiteChain(
+  program: anchor.Program<anchor.Idl>,
+  requiredTaskId: number
+): Promise<PublicKey[]> {
+  const chain: PublicKey[] = [];
+  let nextTaskId = requiredTaskId;
+  while (nextTaskId !== NO_PREREQ_TASK_ID && chain.length < MAX_PREREQ_CHAIN_DEPTH) {
+    const taskPda = getTaskPda(program.programId, nextTaskId);
+    chain.push(taskPda);
+    try {
+      const task = await (program.account as any).taskRecord.fetch(taskPda);
+      nextTaskId = task.requiredTaskId;
+    } catch {
+      break;
+    }
+  }
+  return chain;
+}
+
 function loadKeypair(filePath: string): Keypair {
   const raw = fs.readFileSync(filePath, "utf8");
   return Keypair.fromSecretKey(Uint8Array.from(JSON.parse(raw)));
@@ -742,6 +764,7 @@ async function main() {
       const contentCid = await uploadTaskContent(task.content);
 
       taskSpinner.text = `[${task.taskId}] Creating task on-chain...`;
+      const prerequisiteChain = await getPrerequisiteChain(program, task.requiredTaskId);
       await program.methods
         .createTask(
           task.taskId,
@@ -758,6 +781,9 @@ async function main() {
           task: taskPda,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(
+          prerequisiteChain.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+        )
         .rpc();
 
       taskSpinner.succeed(
@@ -10,6 +10,20 @@ const AGENT_SEED = Buffer.from("agent");
 const TASK_SEED = Buffer.from("task");
 const CLAIM_SEED = Buffer.from("claim");
 const INVITE_SEED = Buffer.from("invite");
+const PUBLISHER_SEED = Buffer.from("publisher");
+const PROGRESS_SEED = Buffer.from("progress");
+const PREREQ_SEED = Buffer.from("prereqs");
+const DISPUTE_CONFIG_SEED = Buffer.from("dispute_config");
+const CHALLENGE_SEED = Buffer.from("challenge");
+const FLAG_SEED = Buffer.from("flag");
+const SLASH_SEED = Buffer.from("slash");
+const APPEAL_SEED = Buffer.from("appeal");
+const TIER_CONFIG_SEED = Buffer.from("tier_config");
+const ECONOMY_CONFIG_SEED = Buffer.from("economy_config");
+const EMISSION_POLICY_SEED = Buffer.from("emission_policy");
+const REWARD_EVENT_SEED = Buffer.from("reward_event");
+const TASK_TOMBSTONE_SEED = Buffer.from("task_tombstone");
+const REJECTION_SEED = Buffer.from("rejection");
 const NO_PREREQ_TASK_ID = 0xffffffff;
 
 function toFixedBytes(input: string, size: number): number[] {
@@ -57,6 +71,38 @@ function getClaimPda(
   )[0];
 }
 
+function getRepeatableClaimPda(
+  programId: PublicKey,
+  taskId: number,
+  agent: PublicKey,
+  completionIndex: number
+): PublicKey {
+  const index = Buffer.alloc(2);
+  index.writeUInt16LE(completionIndex, 0);
+  return PublicKey.findProgramAddressSync(
+    [CLAIM_SEED, taskIdBytes(taskId), agent.toBuffer(), index],
+    programId
+  )[0];
+}
+
+function getProgressPda(
+  programId: PublicKey,
+  taskId: number,
+  agent: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [PROGRESS_SEED, taskIdBytes(taskId), agent.toBuffer()],
+    programId
+  )[0];
+}
+
+function getPrerequisitesPda(programId: PublicKey, taskId: number): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [PREREQ_SEED, taskIdBytes(taskId)],
+    programId
+  )[0];
+}
+
 function getInvitePda(programId: PublicKey, inviter: PublicKey): PublicKey {
   return PublicKey.findProgramAddressSync(
     [INVITE_SEED, inviter.toBuffer()],
@@ -64,6 +110,26 @@ function getInvitePda(programId: PublicKey, inviter: PublicKey): PublicKey {
   )[0];
 }
 
+function getRejectionPda(
+  programId: PublicKey,
+  agent: PublicKey,
+  rejectionIndex: number
+): PublicKey {
+  const index = Buffer.alloc(4);
+  index.writeUInt32LE(rejectionIndex, 0);
+  return PublicKey.findProgramAddressSync(
+    [REJECTION_SEED, agent.toBuffer(), index],
+    programId
+  )[0];
+}
+
+function getPublisherPda(programId: PublicKey, wallet: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [PUBLISHER_SEED, wallet.toBuffer()],
+    programId
+  )[0];
+}
+
 async function airdrop(
   provider: anchor.AnchorProvider,
   pubkey: PublicKey,
@@ -86,6 +152,10 @@ describe("paperclip-protocol", () => {
   const program = anchor.workspace
     .paperclipProtocol as Program<PaperclipProtocol>;
   const protocolPda = getProtocolPda(program.programId);
+  const economyConfigPda = PublicKey.findProgramAddressSync(
+    [ECONOMY_CONFIG_SEED],
+    program.programId
+  )[0];
 
   const baseUnit = new anchor.BN(100);
   const task1Id = 1;
@@ -117,6 +187,7 @@ describe("paperclip-protocol", () => {
       .initialize(baseUnit)
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         authority: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -124,10 +195,16 @@ describe("paperclip-protocol", () => {
 
     const protocol = await program.account.protocolState.fetch(protocolPda);
     assert.equal(protocol.layoutVersion, 1);
-    assert.equal(protocol.reserved.length, 64);
+    assert.equal(protocol.reserved.length, 11);
+    assert.equal(protocol.featureFlags, 0);
     assert.equal(protocol.baseRewardUnit.toNumber(), 100);
     assert.equal(protocol.totalAgents, 0);
     assert.equal(protocol.totalTasks, 0);
+
+    const economy = await program.account.economyConfig.fetch(economyConfigPda);
+    assert.equal(economy.registrationAirdropBps, 10000);
+    assert.equal(economy.inviteeRewardBps, 15000);
+    assert.equal(economy.inviterRewardBps, 5000);
   });
 
   it("Registers agent and airdrops clips", async () => {
@@ -136,6 +213,7 @@ describe("paperclip-protocol", () => {
       .registerAgent()
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         agentAccount: agentPda,
         agent: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -144,7 +222,7 @@ describe("paperclip-protocol", () => {
 
     const agent = await program.account.agentAccount.fetch(agentPda);
     assert.equal(agent.layoutVersion, 1);
-    assert.equal(agent.reserved.length, 88);
+    assert.equal(agent.reserved.length, 48);
     assert.equal(agent.clipsBalance.toNumber(), 100);
     assert.equal(agent.efficiencyTier, 0);
     assert.equal(agent.tasksCompleted, 0);
@@ -164,6 +242,7 @@ describe("paperclip-protocol", () => {
       .registerAgent()
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         agentAccount: inviterAgentPda,
         agent: inviterAgent.publicKey,
         systemProgram: SystemProgram.programId,
@@ -198,6 +277,7 @@ describe("paperclip-protocol", () => {
       .registerAgentWithInvite(Array.from(inviterAgent.publicKey.toBuffer()))
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         agentAccount: invitedAgentPda,
         inviterAgent: inviterAgentPda,
         inviteRecord: invitePda,
@@ -246,6 +326,7 @@ describe("paperclip-protocol", () => {
         .registerAgentWithInvite(Array.from(badCode))
         .accounts({
           protocol: protocolPda,
+          economyConfig: economyConfigPda,
           agentAccount: invalidInviteePda,
           inviterAgent: inviterAgentPda,
           inviteRecord: invitePda,
@@ -283,7 +364,8 @@ describe("paperclip-protocol", () => {
 
     const task = await program.account.taskRecord.fetch(taskPda);
     assert.equal(task.layoutVersion, 1);
-    assert.equal(task.reserved.length, 128);
+    assert.equal(task.reserved.length, 42);
+    assert.equal(task.editCount, 0);
     assert.equal(task.taskId, task1Id);
     assert.equal(task.rewardClips.toNumber(), 50);
     assert.equal(task.maxClaims, 2);
@@ -348,6 +430,40 @@ describe("paperclip-protocol", () => {
     }
   });
 
+  it("Rejects prerequisites that do not exist", async () => {
+    const taskPda = getTaskPda(program.programId, 778);
+    try {
+      await program.methods
+        .createTask(
+          778,
+          toFixedBytes("Missing Prereq", 32),
+          toFixedBytes("bafy-missing-prereq", 64),
+          new anchor.BN(10),
+          1,
+          0,
+          9999
+        )
+        .accounts({
+          protocol: protocolPda,
+          authority: provider.wallet.publicKey,
+          task: taskPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          {
+            pubkey: getTaskPda(program.programId, 9999),
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .rpc();
+      assert.fail("Expected missing prerequisite to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Prerequisite task does not exist");
+    }
+  });
+
   it("Rejects non-authority deactivate_task", async () => {
     const taskPda = getTaskPda(program.programId, task5Id);
     await program.methods
@@ -450,7 +566,9 @@ describe("paperclip-protocol", () => {
     const task = await program.account.taskRecord.fetch(taskPda);
     const claim = await program.account.claimRecord.fetch(claimPda);
     assert.equal(claim.layoutVersion, 1);
-    assert.equal(claim.reserved.length, 64);
+    assert.equal(claim.reserved.length, 38);
+    assert.equal(claim.completionIndex, 0);
+    assert.equal(claim.status, 0);
 
     assert.equal(agent.clipsBalance.toNumber(), 150);
     assert.equal(agent.tasksCompleted, 1);
@@ -550,6 +668,13 @@ describe("paperclip-protocol", () => {
         task: taskPda,
         systemProgram: SystemProgram.programId,
       })
+      .remainingAccounts([
+        {
+          pubkey: getTaskPda(program.programId, task1Id),
+          isWritable: false,
+          isSigner: false,
+        },
+      ])
       .rpc();
 
     const agent4Pda = getAgentPda(program.programId, agent4.publicKey);
@@ -557,6 +682,7 @@ describe("paperclip-protocol", () => {
       .registerAgent()
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         agentAccount: agent4Pda,
         agent: agent4.publicKey,
         systemProgram: SystemProgram.programId,
@@ -649,6 +775,9 @@ describe("paperclip-protocol", () => {
         task: dependentTaskPda,
         systemProgram: SystemProgram.programId,
       })
+      .remainingAccounts([
+        { pubkey: prereqTaskPda, isWritable: false, isSigner: false },
+      ])
       .rpc();
 
     const dependentTask = await program.account.taskRecord.fetch(dependentTaskPda);
@@ -723,6 +852,7 @@ describe("paperclip-protocol", () => {
       .registerAgent()
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         agentAccount: agent2Pda,
         agent: agent2.publicKey,
         systemProgram: SystemProgram.programId,
@@ -749,6 +879,7 @@ describe("paperclip-protocol", () => {
       .registerAgent()
       .accounts({
         protocol: protocolPda,
+        economyConfig: economyConfigPda,
         agentAccount: agent3Pda,
         agent: agent3.publicKey,
         systemProgram: SystemProgram.programId,
@@ -781,4 +912,2683 @@ describe("paperclip-protocol", () => {
       assert.include(message, "Task is fully claimed");
     }
   });
+
+  it("Rejects non-authority pause_protocol", async () => {
+    try {
+      await program.methods
+        .pauseProtocol()
+        .accounts({
+          protocol: protocolPda,
+          authority: unauthorized.publicKey,
+        })
+        .signers([unauthorized])
+        .rpc();
+      assert.fail("Expected unauthorized pause_protocol to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Unauthorized");
+    }
+  });
+
+  it("Pauses protocol and blocks agent instructions until unpaused", async () => {
+    await program.methods
+      .pauseProtocol()
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const paused = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(paused.paused, true);
+
+    const pausedAgent = Keypair.generate();
+    await airdrop(provider, pausedAgent.publicKey);
+    const pausedAgentPda = getAgentPda(program.programId, pausedAgent.publicKey);
+
+    try {
+      await program.methods
+        .registerAgent()
+        .accounts({
+          protocol: protocolPda,
+          economyConfig: economyConfigPda,
+          agentAccount: pausedAgentPda,
+          agent: pausedAgent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([pausedAgent])
+        .rpc();
+      assert.fail("Expected register_agent to fail while paused");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Protocol is paused");
+    }
+
+    const taskPda = getTaskPda(program.programId, task6Id);
+    const agent3Pda = getAgentPda(program.programId, agent3.publicKey);
+    const claimPda = getClaimPda(program.programId, task6Id, agent3.publicKey);
+    try {
+      await program.methods
+        .submitProof(task6Id, toFixedBytes("bafy-proof-paused", 64))
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          agentAccount: agent3Pda,
+          claim: claimPda,
+          agent: agent3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent3])
+        .rpc();
+      assert.fail("Expected submit_proof to fail while paused");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Protocol is paused");
+    }
+
+    await program.methods
+      .unpauseProtocol()
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .registerAgent()
+      .accounts({
+        protocol: protocolPda,
+        economyConfig: economyConfigPda,
+        agentAccount: pausedAgentPda,
+        agent: pausedAgent.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([pausedAgent])
+      .rpc();
+
+    const unpaused = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(unpaused.paused, false);
+  });
+
+  it("Disables invite creation and proof submission independently", async () => {
+    const FEATURE_PROOF_SUBMISSION_DISABLED = 1 << 1;
+    const FEATURE_INVITE_CREATION_DISABLED = 1 << 2;
+
+    await program.methods
+      .setFeatureFlags(FEATURE_INVITE_CREATION_DISABLED)
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const agent2Pda = getAgentPda(program.programId, agent2.publicKey);
+    const agent2InvitePda = getInvitePda(program.programId, agent2.publicKey);
+    try {
+      await program.methods
+        .createInvite()
+        .accounts({
+          protocol: protocolPda,
+          agentAccount: agent2Pda,
+          inviteRecord: agent2InvitePda,
+          agent: agent2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent2])
+        .rpc();
+      assert.fail("Expected create_invite to fail while disabled");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Invite creation is disabled");
+    }
+
+    // Proof submission stays open while only invite creation is disabled.
+    const taskPda = getTaskPda(program.programId, task6Id);
+    const agent3Pda = getAgentPda(program.programId, agent3.publicKey);
+    const claimPda = getClaimPda(program.programId, task6Id, agent3.publicKey);
+    await program.methods
+      .submitProof(task6Id, toFixedBytes("bafy-proof-flags", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        agentAccount: agent3Pda,
+        claim: claimPda,
+        agent: agent3.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent3])
+      .rpc();
+
+    await program.methods
+      .setFeatureFlags(FEATURE_PROOF_SUBMISSION_DISABLED)
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const agent2ClaimPda = getClaimPda(program.programId, task6Id, agent2.publicKey);
+    try {
+      await program.methods
+        .submitProof(task6Id, toFixedBytes("bafy-proof-disabled", 64))
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          agentAccount: agent2Pda,
+          claim: agent2ClaimPda,
+          agent: agent2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent2])
+        .rpc();
+      assert.fail("Expected submit_proof to fail while disabled");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Proof submission is disabled");
+    }
+
+    await program.methods
+      .setFeatureFlags(0)
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const protocol = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(protocol.featureFlags, 0);
+  });
+
+  it("Rotates authority through propose_authority/accept_authority", async () => {
+    const nextAuthority = Keypair.generate();
+    await airdrop(provider, nextAuthority.publicKey);
+
+    await program.methods
+      .proposeAuthority(nextAuthority.publicKey)
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    try {
+      await program.methods
+        .acceptAuthority()
+        .accounts({
+          protocol: protocolPda,
+          newAuthority: unauthorized.publicKey,
+        })
+        .signers([unauthorized])
+        .rpc();
+      assert.fail("Expected accept_authority from wrong signer to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Unauthorized");
+    }
+
+    await program.methods
+      .acceptAuthority()
+      .accounts({
+        protocol: protocolPda,
+        newAuthority: nextAuthority.publicKey,
+      })
+      .signers([nextAuthority])
+      .rpc();
+
+    let protocol = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(protocol.authority.toBase58(), nextAuthority.publicKey.toBase58());
+    assert.equal(protocol.pendingAuthority.toBase58(), PublicKey.default.toBase58());
+
+    // Hand authority back so later tests keep using the provider wallet.
+    await program.methods
+      .proposeAuthority(provider.wallet.publicKey)
+      .accounts({
+        protocol: protocolPda,
+        authority: nextAuthority.publicKey,
+      })
+      .signers([nextAuthority])
+      .rpc();
+    await program.methods
+      .acceptAuthority()
+      .accounts({
+        protocol: protocolPda,
+        newAuthority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    protocol = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(protocol.authority.toBase58(), provider.wallet.publicKey.toBase58());
+  });
+
+  it("Lets publisher roles create and deactivate tasks within their range", async () => {
+    const PUBLISHER_CAN_CREATE = 1 << 0;
+    const publisher = Keypair.generate();
+    await airdrop(provider, publisher.publicKey);
+    const publisherRolePda = getPublisherPda(program.programId, publisher.publicKey);
+
+    await program.methods
+      .grantPublisherRole(publisher.publicKey, PUBLISHER_CAN_CREATE, 1000, 1999)
+      .accounts({
+        protocol: protocolPda,
+        publisherRole: publisherRolePda,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const taskPda = getTaskPda(program.programId, 1000);
+    await program.methods
+      .createTask(
+        1000,
+        toFixedBytes("Published Task", 32),
+        toFixedBytes("bafy-published-task", 64),
+        new anchor.BN(10),
+        5,
+        0,
+        NO_PREREQ_TASK_ID
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: publisher.publicKey,
+        task: taskPda,
+        systemProgram: SystemProgram.programId,
+        publisherRole: publisherRolePda,
+      })
+      .signers([publisher])
+      .rpc();
+
+    const task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.creator.toBase58(), publisher.publicKey.toBase58());
+
+    const outOfRangePda = getTaskPda(program.programId, 2000);
+    try {
+      await program.methods
+        .createTask(
+          2000,
+          toFixedBytes("Out Of Range", 32),
+          toFixedBytes("bafy-out-of-range", 64),
+          new anchor.BN(10),
+          5,
+          0,
+          NO_PREREQ_TASK_ID
+        )
+        .accounts({
+          protocol: protocolPda,
+          authority: publisher.publicKey,
+          task: outOfRangePda,
+          systemProgram: SystemProgram.programId,
+          publisherRole: publisherRolePda,
+        })
+        .signers([publisher])
+        .rpc();
+      assert.fail("Expected out-of-range create_task to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Task id is outside the publisher's allowed range");
+    }
+
+    try {
+      await program.methods
+        .deactivateTask(1000)
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          authority: publisher.publicKey,
+          publisherRole: publisherRolePda,
+        })
+        .signers([publisher])
+        .rpc();
+      assert.fail("Expected deactivate_task without permission to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Unauthorized");
+    }
+
+    await program.methods
+      .revokePublisherRole(publisher.publicKey)
+      .accounts({
+        protocol: protocolPda,
+        publisherRole: publisherRolePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const revoked = await provider.connection.getAccountInfo(publisherRolePda);
+    assert.isNull(revoked);
+  });
+
+  it("Updates a live task in place and tracks edits", async () => {
+    const taskPda = getTaskPda(program.programId, task6Id);
+    const before = await program.account.taskRecord.fetch(taskPda);
+
+    try {
+      await program.methods
+        .updateTask(
+          task6Id,
+          toFixedBytes("Too Few Claims", 32),
+          toFixedBytes("bafy-too-few-claims", 64),
+          new anchor.BN(15),
+          before.currentClaims - 1,
+          0
+        )
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+      assert.fail("Expected lowering max_claims below current_claims to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "max_claims cannot be lower than current_claims");
+    }
+
+    await program.methods
+      .updateTask(
+        task6Id,
+        toFixedBytes("Agent-specific prereq (fixed)", 32),
+        toFixedBytes("bafy-agent-specific-prereq-fixed", 64),
+        new anchor.BN(20),
+        10,
+        0
+      )
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.rewardClips.toNumber(), 20);
+    assert.equal(task.maxClaims, 10);
+    assert.equal(task.currentClaims, before.currentClaims);
+    assert.equal(task.editCount, 1);
+    assert.isAbove(task.updatedAt.toNumber(), 0);
+  });
+
+  it("Reactivates a deactivated task", async () => {
+    const taskPda = getTaskPda(program.programId, task5Id);
+    await program.methods
+      .reactivateTask(task5Id)
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.isActive, true);
+  });
+
+  it("Closes only inactive tasks and returns rent to the creator", async () => {
+    const taskPda = getTaskPda(program.programId, task5Id);
+    const tombstonePda = PublicKey.findProgramAddressSync(
+      [TASK_TOMBSTONE_SEED, taskIdBytes(task5Id)],
+      program.programId
+    )[0];
+
+    try {
+      await program.methods
+        .closeTask(task5Id)
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          prerequisites: null,
+          tombstone: tombstonePda,
+          creator: provider.wallet.publicKey,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Expected close_task on an active task to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Task must be deactivated first");
+    }
+
+    await program.methods
+      .deactivateTask(task5Id)
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const rent = (await provider.connection.getAccountInfo(taskPda))!.lamports;
+    const creatorBefore = await provider.connection.getBalance(provider.wallet.publicKey);
+
+    await program.methods
+      .closeTask(task5Id)
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        prerequisites: null,
+        tombstone: tombstonePda,
+        creator: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const closed = await provider.connection.getAccountInfo(taskPda);
+    assert.isNull(closed);
+    const tombstone = await program.account.taskTombstone.fetch(tombstonePda);
+    assert.equal(tombstone.taskId, task5Id);
+    const tombstoneRent = (await provider.connection.getAccountInfo(tombstonePda))!.lamports;
+    const creatorAfter = await provider.connection.getBalance(provider.wallet.publicKey);
+    // The creator is also the authority here: it pays the tombstone and the
+    // transaction fee, so allow for both.
+    assert.isAbove(creatorAfter, creatorBefore + rent - tombstoneRent - 10_000);
+
+    // Old claims on a closed id must not carry over to a new task.
+    try {
+      await program.methods
+        .createTask(
+          task5Id,
+          toFixedBytes("Task Five Again", 32),
+          toFixedBytes("bafy-task-five-again", 64),
+          new anchor.BN(10),
+          1,
+          0,
+          NO_PREREQ_TASK_ID
+        )
+        .accounts({
+          protocol: protocolPda,
+          authority: provider.wallet.publicKey,
+          task: taskPda,
+          tombstone: tombstonePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Expected re-creating a closed task id to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Task id belongs to a closed task and cannot be reused");
+    }
+  });
+
+  it("Enforces starts_at/ends_at on timed tasks", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const futureTaskId = 20;
+    const expiredTaskId = 21;
+    const futureTaskPda = getTaskPda(program.programId, futureTaskId);
+    const expiredTaskPda = getTaskPda(program.programId, expiredTaskId);
+
+    await program.methods
+      .createTimedTask(
+        futureTaskId,
+        toFixedBytes("Future Event", 32),
+        toFixedBytes("bafy-future-event", 64),
+        new anchor.BN(10),
+        5,
+        0,
+        NO_PREREQ_TASK_ID,
+        new anchor.BN(now + 3600),
+        new anchor.BN(now + 7200)
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: futureTaskPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .createTimedTask(
+        expiredTaskId,
+        toFixedBytes("Past Event", 32),
+        toFixedBytes("bafy-past-event", 64),
+        new anchor.BN(10),
+        5,
+        0,
+        NO_PREREQ_TASK_ID,
+        new anchor.BN(0),
+        new anchor.BN(1)
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: expiredTaskPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const futureTask = await program.account.taskRecord.fetch(futureTaskPda);
+    assert.equal(futureTask.startsAt.toNumber(), now + 3600);
+    assert.equal(futureTask.endsAt.toNumber(), now + 7200);
+
+    const agentPda = getAgentPda(program.programId, provider.wallet.publicKey);
+    const cases: [number, PublicKey, string][] = [
+      [futureTaskId, futureTaskPda, "Task has not started yet"],
+      [expiredTaskId, expiredTaskPda, "Task has expired"],
+    ];
+    for (const [taskId, taskPda, expected] of cases) {
+      try {
+        await program.methods
+          .submitProof(taskId, toFixedBytes("bafy-timed-proof", 64))
+          .accounts({
+            protocol: protocolPda,
+            task: taskPda,
+            agentAccount: agentPda,
+            claim: getClaimPda(program.programId, taskId, provider.wallet.publicKey),
+            agent: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail(`Expected submit_proof on task ${taskId} to fail`);
+      } catch (err) {
+        const message = (err as Error).toString();
+        assert.include(message, expected);
+      }
+    }
+  });
+
+  it("Allows repeatable claims per completion index with a cooldown", async () => {
+    const dailyTaskId = 30;
+    const taskPda = getTaskPda(program.programId, dailyTaskId);
+    await program.methods
+      .createTask(
+        dailyTaskId,
+        toFixedBytes("Daily Check-in", 32),
+        toFixedBytes("bafy-daily-check-in", 64),
+        new anchor.BN(5),
+        100,
+        0,
+        NO_PREREQ_TASK_ID
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: taskPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .setTaskRepeatable(dailyTaskId, 86400, 7)
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const agentPda = getAgentPda(program.programId, agent2.publicKey);
+    const progressPda = getProgressPda(program.programId, dailyTaskId, agent2.publicKey);
+
+    try {
+      await program.methods
+        .submitProof(dailyTaskId, toFixedBytes("bafy-daily-single", 64))
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          agentAccount: agentPda,
+          claim: getClaimPda(program.programId, dailyTaskId, agent2.publicKey),
+          agent: agent2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent2])
+        .rpc();
+      assert.fail("Expected submit_proof on a repeatable task to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Repeatable tasks must be claimed with submit_repeatable_proof");
+    }
+
+    const firstClaimPda = getRepeatableClaimPda(
+      program.programId,
+      dailyTaskId,
+      agent2.publicKey,
+      0
+    );
+    await program.methods
+      .submitRepeatableProof(dailyTaskId, 0, toFixedBytes("bafy-daily-day-one", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        agentAccount: agentPda,
+        progress: progressPda,
+        claim: firstClaimPda,
+        agent: agent2.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent2])
+      .rpc();
+
+    const progress = await program.account.taskProgress.fetch(progressPda);
+    assert.equal(progress.completions, 1);
+    assert.isAbove(progress.lastClaimAt.toNumber(), 0);
+    const firstClaim = await program.account.claimRecord.fetch(firstClaimPda);
+    assert.equal(firstClaim.completionIndex, 0);
+
+    try {
+      await program.methods
+        .submitRepeatableProof(dailyTaskId, 1, toFixedBytes("bafy-daily-too-soon", 64))
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          agentAccount: agentPda,
+          progress: progressPda,
+          claim: getRepeatableClaimPda(program.programId, dailyTaskId, agent2.publicKey, 1),
+          agent: agent2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent2])
+        .rpc();
+      assert.fail("Expected cooldown to block the second completion");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Task cooldown has not elapsed");
+    }
+
+    // A repeatable prerequisite is met by the agent's first completion.
+    const followUpTaskId = 31;
+    const followUpPda = getTaskPda(program.programId, followUpTaskId);
+    await program.methods
+      .createTask(
+        followUpTaskId,
+        toFixedBytes("Daily Follow-up", 32),
+        toFixedBytes("bafy-daily-follow-up", 64),
+        new anchor.BN(5),
+        100,
+        0,
+        dailyTaskId
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: followUpPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const followUpClaimPda = getClaimPda(program.programId, followUpTaskId, agent2.publicKey);
+    await program.methods
+      .submitProof(followUpTaskId, toFixedBytes("bafy-daily-follow-up-proof", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: followUpPda,
+        agentAccount: agentPda,
+        claim: followUpClaimPda,
+        agent: agent2.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([{ pubkey: firstClaimPda, isWritable: false, isSigner: false }])
+      .signers([agent2])
+      .rpc();
+
+    const followUpClaim = await program.account.claimRecord.fetch(followUpClaimPda);
+    assert.equal(followUpClaim.taskId, followUpTaskId);
+  });
+
+  it("Enforces all-of and any-of prerequisite sets", async () => {
+    const PREREQ_MODE_ALL = 0;
+    const PREREQ_MODE_ANY = 1;
+    const baseA = 40;
+    const baseB = 41;
+    const anyOfTask = 42;
+    const allOfTask = 43;
+
+    for (const taskId of [baseA, baseB, anyOfTask, allOfTask]) {
+      await program.methods
+        .createTask(
+          taskId,
+          toFixedBytes(`Prereq Set ${taskId}`, 32),
+          toFixedBytes(`bafy-prereq-set-${taskId}`, 64),
+          new anchor.BN(10),
+          10,
+          0,
+          NO_PREREQ_TASK_ID
+        )
+        .accounts({
+          protocol: protocolPda,
+          authority: provider.wallet.publicKey,
+          task: getTaskPda(program.programId, taskId),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    for (const [taskId, mode] of [
+      [anyOfTask, PREREQ_MODE_ANY],
+      [allOfTask, PREREQ_MODE_ALL],
+    ]) {
+      await program.methods
+        .setTaskPrerequisites(taskId, mode, [baseA, baseB])
+        .accounts({
+          protocol: protocolPda,
+          task: getTaskPda(program.programId, taskId),
+          prerequisites: getPrerequisitesPda(program.programId, taskId),
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          [baseA, baseB].map((id) => ({
+            pubkey: getTaskPda(program.programId, id),
+            isWritable: false,
+            isSigner: false,
+          }))
+        )
+        .rpc();
+    }
+
+    const agent3Pda = getAgentPda(program.programId, agent3.publicKey);
+    await program.methods
+      .submitProof(baseA, toFixedBytes("bafy-prereq-base-a", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: getTaskPda(program.programId, baseA),
+        agentAccount: agent3Pda,
+        claim: getClaimPda(program.programId, baseA, agent3.publicKey),
+        agent: agent3.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent3])
+      .rpc();
+
+    const prereqClaims = [baseA, baseB].map((taskId) => ({
+      pubkey: getClaimPda(program.programId, taskId, agent3.publicKey),
+      isWritable: false,
+      isSigner: false,
+    }));
+
+    try {
+      await program.methods
+        .submitProof(allOfTask, toFixedBytes("bafy-all-of", 64))
+        .accounts({
+          protocol: protocolPda,
+          task: getTaskPda(program.programId, allOfTask),
+          agentAccount: agent3Pda,
+          claim: getClaimPda(program.programId, allOfTask, agent3.publicKey),
+          agent: agent3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          {
+            pubkey: getPrerequisitesPda(program.programId, allOfTask),
+            isWritable: false,
+            isSigner: false,
+          },
+          ...prereqClaims,
+        ])
+        .signers([agent3])
+        .rpc();
+      assert.fail("Expected all-of prerequisite set to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Required prerequisite task has not been completed");
+    }
+
+    const anyOfClaimPda = getClaimPda(program.programId, anyOfTask, agent3.publicKey);
+    await program.methods
+      .submitProof(anyOfTask, toFixedBytes("bafy-any-of", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: getTaskPda(program.programId, anyOfTask),
+        agentAccount: agent3Pda,
+        claim: anyOfClaimPda,
+        agent: agent3.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        {
+          pubkey: getPrerequisitesPda(program.programId, anyOfTask),
+          isWritable: false,
+          isSigner: false,
+        },
+        ...prereqClaims,
+      ])
+      .signers([agent3])
+      .rpc();
+
+    const claim = await program.account.claimRecord.fetch(anyOfClaimPda);
+    assert.equal(claim.taskId, anyOfTask);
+  });
+
+  it("Rejects prerequisite sets that list missing tasks or form a cycle", async () => {
+    const PREREQ_MODE_ALL = 0;
+    const baseA = 40;
+    const allOfTask = 43;
+    const setPrerequisites = (
+      taskId: number,
+      taskIds: number[],
+      remainingAccounts: PublicKey[]
+    ) =>
+      program.methods
+        .setTaskPrerequisites(taskId, PREREQ_MODE_ALL, taskIds)
+        .accounts({
+          protocol: protocolPda,
+          task: getTaskPda(program.programId, taskId),
+          prerequisites: getPrerequisitesPda(program.programId, taskId),
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          remainingAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+        )
+        .rpc();
+
+    try {
+      await setPrerequisites(baseA, [9999], [getTaskPda(program.programId, 9999)]);
+      assert.fail("Expected a missing prerequisite to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Prerequisite task does not exist");
+    }
+
+    // Task 43 already requires task 40 through its set, so 40 cannot require 43.
+    try {
+      await setPrerequisites(
+        baseA,
+        [allOfTask],
+        [getTaskPda(program.programId, allOfTask), getPrerequisitesPda(program.programId, allOfTask)]
+      );
+      assert.fail("Expected a prerequisite cycle to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Task prerequisites form a cycle");
+    }
+
+    const baseATask = await program.account.taskRecord.fetch(getTaskPda(program.programId, baseA));
+    assert.isFalse(baseATask.hasPrerequisiteSet);
+  });
+
+  it("Holds optimistic claims pending until finalized or rejected", async () => {
+    const VERIFICATION_OPTIMISTIC = 1;
+    const CLAIM_STATUS_APPROVED = 0;
+    const CLAIM_STATUS_PENDING = 1;
+    const optimisticTaskId = 50;
+    const taskPda = getTaskPda(program.programId, optimisticTaskId);
+
+    await program.methods
+      .createTask(
+        optimisticTaskId,
+        toFixedBytes("Optimistic Task", 32),
+        toFixedBytes("bafy-optimistic-task", 64),
+        new anchor.BN(40),
+        5,
+        0,
+        NO_PREREQ_TASK_ID
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: taskPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .setTaskVerification(optimisticTaskId, VERIFICATION_OPTIMISTIC, 3600)
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const agent2Pda = getAgentPda(program.programId, agent2.publicKey);
+    const agent2ClaimPda = getClaimPda(program.programId, optimisticTaskId, agent2.publicKey);
+    const agentBefore = await program.account.agentAccount.fetch(agent2Pda);
+
+    await program.methods
+      .submitProof(optimisticTaskId, toFixedBytes("bafy-optimistic-proof", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        agentAccount: agent2Pda,
+        claim: agent2ClaimPda,
+        agent: agent2.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent2])
+      .rpc();
+
+    const pending = await program.account.claimRecord.fetch(agent2ClaimPda);
+    assert.equal(pending.status, CLAIM_STATUS_PENDING);
+    assert.equal(pending.clipsAwarded.toNumber(), 40);
+    let agentAfter = await program.account.agentAccount.fetch(agent2Pda);
+    assert.equal(agentAfter.clipsBalance.toNumber(), agentBefore.clipsBalance.toNumber());
+
+    try {
+      await program.methods
+        .finalizeClaim()
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          claim: agent2ClaimPda,
+          agentAccount: agent2Pda,
+        })
+        .rpc();
+      assert.fail("Expected finalize_claim inside the window to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Challenge window is still open");
+    }
+
+    const rejectionPda = getRejectionPda(
+      program.programId,
+      agent2.publicKey,
+      agentAfter.rejectionCount
+    );
+    await program.methods
+      .rejectClaim()
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        claim: agent2ClaimPda,
+        agentAccount: agent2Pda,
+        rejection: rejectionPda,
+        agent: agent2.publicKey,
+        reviewer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(agent2ClaimPda));
+    const rejection = await program.account.rejectionRecord.fetch(rejectionPda);
+    assert.equal(rejection.taskId, optimisticTaskId);
+    assert.equal(rejection.clipsAwarded.toNumber(), 40);
+    let task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.currentClaims, 0);
+
+    // With a zero-length window the claim is finalizable straight away.
+    await program.methods
+      .setTaskVerification(optimisticTaskId, VERIFICATION_OPTIMISTIC, 0)
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .submitProof(optimisticTaskId, toFixedBytes("bafy-optimistic-retry", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        agentAccount: agent2Pda,
+        claim: agent2ClaimPda,
+        agent: agent2.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent2])
+      .rpc();
+
+    await program.methods
+      .finalizeClaim()
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        claim: agent2ClaimPda,
+        agentAccount: agent2Pda,
+      })
+      .rpc();
+
+    const finalized = await program.account.claimRecord.fetch(agent2ClaimPda);
+    assert.equal(finalized.status, CLAIM_STATUS_APPROVED);
+    agentAfter = await program.account.agentAccount.fetch(agent2Pda);
+    assert.equal(
+      agentAfter.clipsBalance.toNumber(),
+      agentBefore.clipsBalance.toNumber() + 40
+    );
+    assert.equal(agentAfter.tasksCompleted, agentBefore.tasksCompleted + 1);
+    task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.currentClaims, 1);
+  });
+
+  it("Routes validator-mode claims through approve_claim/reject_claim", async () => {
+    const CLAIM_STATUS_APPROVED = 0;
+    const CLAIM_STATUS_AWAITING_VALIDATOR = 2;
+    const validatorTaskId = 51;
+    const validator = Keypair.generate();
+    await airdrop(provider, validator.publicKey);
+    const taskPda = getTaskPda(program.programId, validatorTaskId);
+
+    await program.methods
+      .createTask(
+        validatorTaskId,
+        toFixedBytes("Validated Task", 32),
+        toFixedBytes("bafy-validated-task", 64),
+        new anchor.BN(200),
+        5,
+        0,
+        NO_PREREQ_TASK_ID
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: taskPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .setTaskValidator(validatorTaskId, validator.publicKey)
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const agent3Pda = getAgentPda(program.programId, agent3.publicKey);
+    const claimPda = getClaimPda(program.programId, validatorTaskId, agent3.publicKey);
+    const agentBefore = await program.account.agentAccount.fetch(agent3Pda);
+    const protocolBefore = await program.account.protocolState.fetch(protocolPda);
+
+    await program.methods
+      .submitProof(validatorTaskId, toFixedBytes("bafy-validated-proof", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        agentAccount: agent3Pda,
+        claim: claimPda,
+        agent: agent3.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent3])
+      .rpc();
+
+    const pending = await program.account.claimRecord.fetch(claimPda);
+    assert.equal(pending.status, CLAIM_STATUS_AWAITING_VALIDATOR);
+
+    try {
+      await program.methods
+        .approveClaim()
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          claim: claimPda,
+          agentAccount: agent3Pda,
+          validator: unauthorized.publicKey,
+        })
+        .signers([unauthorized])
+        .rpc();
+      assert.fail("Expected approve_claim from a non-validator to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Unauthorized");
+    }
+
+    await program.methods
+      .approveClaim()
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        claim: claimPda,
+        agentAccount: agent3Pda,
+        validator: validator.publicKey,
+      })
+      .signers([validator])
+      .rpc();
+
+    const approved = await program.account.claimRecord.fetch(claimPda);
+    assert.equal(approved.status, CLAIM_STATUS_APPROVED);
+    const agentAfter = await program.account.agentAccount.fetch(agent3Pda);
+    const protocolAfter = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(
+      agentAfter.clipsBalance.toNumber(),
+      agentBefore.clipsBalance.toNumber() + 200
+    );
+    assert.equal(agentAfter.tasksCompleted, agentBefore.tasksCompleted + 1);
+    assert.equal(
+      protocolAfter.totalClipsDistributed.toNumber(),
+      protocolBefore.totalClipsDistributed.toNumber() + 200
+    );
+
+    const agent4Pda = getAgentPda(program.programId, agent4.publicKey);
+    const agent4ClaimPda = getClaimPda(program.programId, validatorTaskId, agent4.publicKey);
+    await program.methods
+      .submitProof(validatorTaskId, toFixedBytes("bafy-validated-weak", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        agentAccount: agent4Pda,
+        claim: agent4ClaimPda,
+        agent: agent4.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent4])
+      .rpc();
+
+    // The awaiting claim keeps the task in validator mode and open.
+    try {
+      await program.methods
+        .setTaskVerification(validatorTaskId, 0, 0)
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+      assert.fail("Expected leaving validator mode with an unsettled claim to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Task still has pending claims to settle");
+    }
+    let task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.unsettledClaims, 1);
+
+    const agent4Account = await program.account.agentAccount.fetch(agent4Pda);
+    const rejectionPda = getRejectionPda(
+      program.programId,
+      agent4.publicKey,
+      agent4Account.rejectionCount
+    );
+    await program.methods
+      .rejectClaim()
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        claim: agent4ClaimPda,
+        agentAccount: agent4Pda,
+        rejection: rejectionPda,
+        agent: agent4.publicKey,
+        reviewer: validator.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([validator])
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(agent4ClaimPda));
+    const rejection = await program.account.rejectionRecord.fetch(rejectionPda);
+    assert.equal(rejection.rejectedBy.toBase58(), validator.publicKey.toBase58());
+    task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.unsettledClaims, 0);
+  });
+
+  it("Stakes, upholds and settles a challenge against an approved claim", async () => {
+    const CLAIM_STATUS_REVOKED = 3;
+    const CHALLENGE_STATUS_UPHELD = 1;
+    const disputeConfigPda = PublicKey.findProgramAddressSync(
+      [DISPUTE_CONFIG_SEED],
+      program.programId
+    )[0];
+
+    await program.methods
+      .setDisputeConfig(0, new anchor.BN(10), 5000, false, 3)
+      .accounts({
+        protocol: protocolPda,
+        disputeConfig: disputeConfigPda,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Challenge the provider's approved claim on task one (50 Clips).
+    const claimPda = getClaimPda(program.programId, task1Id, provider.wallet.publicKey);
+    const challengePda = PublicKey.findProgramAddressSync(
+      [CHALLENGE_SEED, claimPda.toBuffer()],
+      program.programId
+    )[0];
+    const claimantPda = getAgentPda(program.programId, provider.wallet.publicKey);
+    const challengerPda = getAgentPda(program.programId, agent4.publicKey);
+
+    try {
+      await program.methods
+        .challengeClaim(new anchor.BN(5), toFixedBytes("bafy-low-stake", 64))
+        .accounts({
+          protocol: protocolPda,
+          disputeConfig: disputeConfigPda,
+          claim: claimPda,
+          challenge: challengePda,
+          challengerAgent: challengerPda,
+          challenger: agent4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent4])
+        .rpc();
+      assert.fail("Expected a stake below the minimum to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Challenge stake is below the minimum");
+    }
+
+    const claimantBefore = await program.account.agentAccount.fetch(claimantPda);
+    const challengerBefore = await program.account.agentAccount.fetch(challengerPda);
+    const protocolBefore = await program.account.protocolState.fetch(protocolPda);
+
+    await program.methods
+      .challengeClaim(new anchor.BN(20), toFixedBytes("bafy-challenge-evidence", 64))
+      .accounts({
+        protocol: protocolPda,
+        disputeConfig: disputeConfigPda,
+        claim: claimPda,
+        challenge: challengePda,
+        challengerAgent: challengerPda,
+        challenger: agent4.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent4])
+      .rpc();
+
+    let challenger = await program.account.agentAccount.fetch(challengerPda);
+    assert.equal(
+      challenger.clipsBalance.toNumber(),
+      challengerBefore.clipsBalance.toNumber() - 20
+    );
+
+    await program.methods
+      .resolveChallenge(true)
+      .accounts({
+        protocol: protocolPda,
+        disputeConfig: disputeConfigPda,
+        claim: claimPda,
+        challenge: challengePda,
+        claimantAgent: claimantPda,
+        challengerAgent: challengerPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const claim = await program.account.claimRecord.fetch(claimPda);
+    const challenge = await program.account.challengeRecord.fetch(challengePda);
+    const claimant = await program.account.agentAccount.fetch(claimantPda);
+    const protocolAfter = await program.account.protocolState.fetch(protocolPda);
+    challenger = await program.account.agentAccount.fetch(challengerPda);
+
+    assert.equal(claim.status, CLAIM_STATUS_REVOKED);
+    assert.equal(claim.clipsClawedBack.toNumber(), 50);
+    assert.equal(challenge.status, CHALLENGE_STATUS_UPHELD);
+    assert.equal(
+      claimant.clipsBalance.toNumber(),
+      claimantBefore.clipsBalance.toNumber() - 50
+    );
+    // Stake back plus half of the 50 Clips clawback.
+    assert.equal(
+      challenger.clipsBalance.toNumber(),
+      challengerBefore.clipsBalance.toNumber() + 25
+    );
+    // The other 25 Clips are burned, not taken out of the distributed total.
+    assert.equal(
+      protocolAfter.totalClipsDistributed.toNumber(),
+      protocolBefore.totalClipsDistributed.toNumber()
+    );
+    assert.equal(
+      protocolAfter.clipsBurned.toNumber(),
+      protocolBefore.clipsBurned.toNumber() + 25
+    );
+  });
+
+  it("Dismisses challenges by forfeiting or burning the stake", async () => {
+    const CLAIM_STATUS_APPROVED = 0;
+    const CHALLENGE_STATUS_DISMISSED = 2;
+    const disputeConfigPda = PublicKey.findProgramAddressSync(
+      [DISPUTE_CONFIG_SEED],
+      program.programId
+    )[0];
+    const challengerPda = getAgentPda(program.programId, agent4.publicKey);
+
+    const challengeAndDismiss = async (taskId: number, claimant: Keypair, forfeit: boolean) => {
+      await program.methods
+        .setDisputeConfig(0, new anchor.BN(10), 5000, forfeit, 3)
+        .accounts({
+          protocol: protocolPda,
+          disputeConfig: disputeConfigPda,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const claimantPda = getAgentPda(program.programId, claimant.publicKey);
+      const claimPda = getClaimPda(program.programId, taskId, claimant.publicKey);
+      const challengePda = PublicKey.findProgramAddressSync(
+        [CHALLENGE_SEED, claimPda.toBuffer()],
+        program.programId
+      )[0];
+      const claimantBefore = await program.account.agentAccount.fetch(claimantPda);
+      const challengerBefore = await program.account.agentAccount.fetch(challengerPda);
+      const protocolBefore = await program.account.protocolState.fetch(protocolPda);
+
+      await program.methods
+        .challengeClaim(new anchor.BN(10), toFixedBytes("bafy-weak-evidence", 64))
+        .accounts({
+          protocol: protocolPda,
+          disputeConfig: disputeConfigPda,
+          claim: claimPda,
+          challenge: challengePda,
+          challengerAgent: challengerPda,
+          challenger: agent4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent4])
+        .rpc();
+
+      await program.methods
+        .resolveChallenge(false)
+        .accounts({
+          protocol: protocolPda,
+          disputeConfig: disputeConfigPda,
+          claim: claimPda,
+          challenge: challengePda,
+          claimantAgent: claimantPda,
+          challengerAgent: challengerPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const claim = await program.account.claimRecord.fetch(claimPda);
+      const challenge = await program.account.challengeRecord.fetch(challengePda);
+      assert.equal(claim.status, CLAIM_STATUS_APPROVED);
+      assert.equal(challenge.status, CHALLENGE_STATUS_DISMISSED);
+      const challenger = await program.account.agentAccount.fetch(challengerPda);
+      assert.equal(
+        challenger.clipsBalance.toNumber(),
+        challengerBefore.clipsBalance.toNumber() - 10
+      );
+      return {
+        claimantBefore,
+        claimant: await program.account.agentAccount.fetch(claimantPda),
+        protocolBefore,
+        protocolAfter: await program.account.protocolState.fetch(protocolPda),
+      };
+    };
+
+    // Forfeited: the claimant keeps the challenger's stake.
+    let result = await challengeAndDismiss(51, agent3, true);
+    assert.equal(
+      result.claimant.clipsBalance.toNumber(),
+      result.claimantBefore.clipsBalance.toNumber() + 10
+    );
+    assert.equal(
+      result.protocolAfter.clipsBurned.toNumber(),
+      result.protocolBefore.clipsBurned.toNumber()
+    );
+
+    // Burned: nobody receives the stake.
+    result = await challengeAndDismiss(50, agent2, false);
+    assert.equal(
+      result.claimant.clipsBalance.toNumber(),
+      result.claimantBefore.clipsBalance.toNumber()
+    );
+    assert.equal(
+      result.protocolAfter.clipsBurned.toNumber(),
+      result.protocolBefore.clipsBurned.toNumber() + 10
+    );
+    assert.equal(
+      result.protocolAfter.totalClipsDistributed.toNumber(),
+      result.protocolBefore.totalClipsDistributed.toNumber()
+    );
+  });
+
+  it("Rejects claim flags from agents below the flagger tier", async () => {
+    const claimPda = getClaimPda(program.programId, task1Id, provider.wallet.publicKey);
+    const flagPda = PublicKey.findProgramAddressSync(
+      [FLAG_SEED, claimPda.toBuffer(), agent4.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+    try {
+      await program.methods
+        .flagClaim(1, toFixedBytes("bafy-flag-evidence", 64))
+        .accounts({
+          protocol: protocolPda,
+          disputeConfig: null,
+          claim: claimPda,
+          flag: flagPda,
+          flaggerAgent: getAgentPda(program.programId, agent4.publicKey),
+          flagger: agent4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent4])
+        .rpc();
+      assert.fail("Expected a tier 0 flagger to be rejected");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Agent tier is too low to flag claims");
+    }
+
+    const claim = await program.account.claimRecord.fetch(claimPda);
+    assert.equal(claim.flagCount, 0);
+    assert.isFalse(claim.payoutFrozen);
+  });
+
+  it("Slashes an agent and records the slash", async () => {
+    const agentPda = getAgentPda(program.programId, agent4.publicKey);
+    const agentBefore = await program.account.agentAccount.fetch(agentPda);
+    const protocolBefore = await program.account.protocolState.fetch(protocolPda);
+    const index = Buffer.alloc(4);
+    index.writeUInt32LE(agentBefore.slashCount, 0);
+    const slashPda = PublicKey.findProgramAddressSync(
+      [SLASH_SEED, agent4.publicKey.toBuffer(), index],
+      program.programId
+    )[0];
+
+    try {
+      await program.methods
+        .slashAgent(new anchor.BN(10), 2, toFixedBytes("bafy-slash-evidence", 64))
+        .accounts({
+          protocol: protocolPda,
+          agentAccount: agentPda,
+          slashRecord: slashPda,
+          authority: agent4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent4])
+        .rpc();
+      assert.fail("Expected non-authority slash_agent to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Unauthorized");
+    }
+
+    await program.methods
+      .slashAgent(new anchor.BN(10), 2, toFixedBytes("bafy-slash-evidence", 64))
+      .accounts({
+        protocol: protocolPda,
+        agentAccount: agentPda,
+        slashRecord: slashPda,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const slash = await program.account.slashRecord.fetch(slashPda);
+    const agentAfter = await program.account.agentAccount.fetch(agentPda);
+    const protocolAfter = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(slash.amount.toNumber(), 10);
+    assert.equal(slash.reasonCode, 2);
+    assert.equal(slash.index, agentBefore.slashCount);
+    assert.equal(
+      agentAfter.clipsBalance.toNumber(),
+      agentBefore.clipsBalance.toNumber() - 10
+    );
+    assert.equal(agentAfter.clipsSlashed.toNumber(), agentBefore.clipsSlashed.toNumber() + 10);
+    assert.equal(agentAfter.slashCount, agentBefore.slashCount + 1);
+    assert.equal(
+      protocolAfter.clipsSlashed.toNumber(),
+      protocolBefore.clipsSlashed.toNumber() + 10
+    );
+    assert.equal(
+      protocolAfter.totalClipsDistributed.toNumber(),
+      protocolBefore.totalClipsDistributed.toNumber()
+    );
+  });
+
+  it("Freezes an agent until unfrozen or the expiry passes", async () => {
+    const agentPda = getAgentPda(program.programId, agent4.publicKey);
+    const invitePda = getInvitePda(program.programId, agent4.publicKey);
+    const freezeAccounts = {
+      protocol: protocolPda,
+      agentAccount: agentPda,
+      authority: provider.wallet.publicKey,
+    };
+
+    try {
+      await program.methods.freezeAgent(new anchor.BN(1)).accounts(freezeAccounts).rpc();
+      assert.fail("Expected a past freeze expiry to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Freeze expiry must be in the future");
+    }
+
+    await program.methods.freezeAgent(null).accounts(freezeAccounts).rpc();
+    const frozen = await program.account.agentAccount.fetch(agentPda);
+    assert.isTrue(frozen.frozenUntil.gt(new anchor.BN(0)));
+
+    try {
+      await program.methods
+        .createInvite()
+        .accounts({
+          protocol: protocolPda,
+          agentAccount: agentPda,
+          inviteRecord: invitePda,
+          agent: agent4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent4])
+        .rpc();
+      assert.fail("Expected create_invite to fail for a frozen agent");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Agent is frozen");
+    }
+
+    const optimisticTaskId = 50;
+    try {
+      await program.methods
+        .submitProof(optimisticTaskId, toFixedBytes("bafy-frozen-proof", 64))
+        .accounts({
+          protocol: protocolPda,
+          task: getTaskPda(program.programId, optimisticTaskId),
+          agentAccount: agentPda,
+          claim: getClaimPda(program.programId, optimisticTaskId, agent4.publicKey),
+          agent: agent4.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent4])
+        .rpc();
+      assert.fail("Expected submit_proof to fail for a frozen agent");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Agent is frozen");
+    }
+
+    await program.methods.unfreezeAgent().accounts(freezeAccounts).rpc();
+    await program.methods
+      .createInvite()
+      .accounts({
+        protocol: protocolPda,
+        agentAccount: agentPda,
+        inviteRecord: invitePda,
+        agent: agent4.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent4])
+      .rpc();
+
+    const unfrozen = await program.account.agentAccount.fetch(agentPda);
+    assert.equal(unfrozen.frozenUntil.toNumber(), 0);
+
+    // A timed freeze blocks the agent's invite until it expires.
+    const invitee = Keypair.generate();
+    await airdrop(provider, invitee.publicKey);
+    const inviteePda = getAgentPda(program.programId, invitee.publicKey);
+    const registerInvitee = () =>
+      program.methods
+        .registerAgentWithInvite(Array.from(agent4.publicKey.toBuffer()))
+        .accounts({
+          protocol: protocolPda,
+          economyConfig: economyConfigPda,
+          agentAccount: inviteePda,
+          inviterAgent: agentPda,
+          inviteRecord: invitePda,
+          agent: invitee.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([invitee])
+        .rpc();
+
+    await program.methods
+      .freezeAgent(new anchor.BN(Math.floor(Date.now() / 1000) + 3))
+      .accounts(freezeAccounts)
+      .rpc();
+    try {
+      await registerInvitee();
+      assert.fail("Expected an invite from a frozen inviter to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Agent is frozen");
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 4500));
+    await registerInvitee();
+    const registered = await program.account.agentAccount.fetch(inviteePda);
+    assert.equal(registered.invitedBy.toBase58(), agent4.publicKey.toBase58());
+  });
+
+  it("Files and resolves appeals against slashes and rejected claims", async () => {
+    const APPEAL_STATUS_UPHELD = 1;
+    const CLAIM_STATUS_APPROVED = 0;
+    const getAppealPda = (subject: PublicKey) =>
+      PublicKey.findProgramAddressSync([APPEAL_SEED, subject.toBuffer()], program.programId)[0];
+
+    // agent4's first slash, written by the slash_agent test above.
+    const agent4Pda = getAgentPda(program.programId, agent4.publicKey);
+    const slashPda = PublicKey.findProgramAddressSync(
+      [SLASH_SEED, agent4.publicKey.toBuffer(), Buffer.alloc(4)],
+      program.programId
+    )[0];
+    const slashAppealPda = getAppealPda(slashPda);
+
+    await program.methods
+      .fileAppeal(toFixedBytes("bafy-slash-appeal", 64))
+      .accounts({
+        protocol: protocolPda,
+        slashRecord: slashPda,
+        claim: null,
+        rejection: null,
+        appeal: slashAppealPda,
+        appellant: agent4.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent4])
+      .rpc();
+
+    const agent4Before = await program.account.agentAccount.fetch(agent4Pda);
+    await program.methods
+      .resolveAppeal(true)
+      .accounts({
+        protocol: protocolPda,
+        appeal: slashAppealPda,
+        agentAccount: agent4Pda,
+        slashRecord: slashPda,
+        claim: null,
+        rejection: null,
+        task: null,
+        authority: provider.wallet.publicKey,
+        restoredClaim: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const slashAppeal = await program.account.appealRecord.fetch(slashAppealPda);
+    const agent4After = await program.account.agentAccount.fetch(agent4Pda);
+    assert.equal(slashAppeal.status, APPEAL_STATUS_UPHELD);
+    assert.equal(slashAppeal.clipsRestored.toNumber(), 10);
+    assert.equal(
+      agent4After.clipsBalance.toNumber(),
+      agent4Before.clipsBalance.toNumber() + 10
+    );
+    assert.equal(agent4After.clipsSlashed.toNumber(), agent4Before.clipsSlashed.toNumber() - 10);
+
+    // agent4's validator-mode claim was closed by reject_claim, leaving its
+    // first rejection record.
+    const validatorTaskId = 51;
+    const taskPda = getTaskPda(program.programId, validatorTaskId);
+    const rejectionPda = getRejectionPda(program.programId, agent4.publicKey, 0);
+    const restoredClaimPda = getClaimPda(program.programId, validatorTaskId, agent4.publicKey);
+    const rejectionAppealPda = getAppealPda(rejectionPda);
+
+    try {
+      await program.methods
+        .fileAppeal(toFixedBytes("bafy-not-my-rejection", 64))
+        .accounts({
+          protocol: protocolPda,
+          slashRecord: null,
+          claim: null,
+          rejection: rejectionPda,
+          appeal: rejectionAppealPda,
+          appellant: agent3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent3])
+        .rpc();
+      assert.fail("Expected an appeal by another agent to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Unauthorized");
+    }
+
+    await program.methods
+      .fileAppeal(toFixedBytes("bafy-rejection-appeal", 64))
+      .accounts({
+        protocol: protocolPda,
+        slashRecord: null,
+        claim: null,
+        rejection: rejectionPda,
+        appeal: rejectionAppealPda,
+        appellant: agent4.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent4])
+      .rpc();
+
+    const rejectedBefore = await program.account.agentAccount.fetch(agent4Pda);
+    const taskBefore = await program.account.taskRecord.fetch(taskPda);
+    await program.methods
+      .resolveAppeal(true)
+      .accounts({
+        protocol: protocolPda,
+        appeal: rejectionAppealPda,
+        agentAccount: agent4Pda,
+        slashRecord: null,
+        claim: null,
+        rejection: rejectionPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+        restoredClaim: restoredClaimPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const restored = await program.account.claimRecord.fetch(restoredClaimPda);
+    const rejectedAfter = await program.account.agentAccount.fetch(agent4Pda);
+    const taskAfter = await program.account.taskRecord.fetch(taskPda);
+    const rejectionAppeal = await program.account.appealRecord.fetch(rejectionAppealPda);
+    assert.equal(restored.status, CLAIM_STATUS_APPROVED);
+    assert.equal(restored.clipsAwarded.toNumber(), 200);
+    assert.equal(rejectionAppeal.clipsRestored.toNumber(), 200);
+    assert.equal(
+      rejectedAfter.clipsBalance.toNumber(),
+      rejectedBefore.clipsBalance.toNumber() + 200
+    );
+    assert.equal(taskAfter.currentClaims, taskBefore.currentClaims + 1);
+
+    // The provider's task one claim was revoked by the upheld challenge; only
+    // the clawed-back amount is repaid.
+    const revokedPda = getClaimPda(program.programId, task1Id, provider.wallet.publicKey);
+    const revokedAppealPda = getAppealPda(revokedPda);
+    const providerPda = getAgentPda(program.programId, provider.wallet.publicKey);
+    const revoked = await program.account.claimRecord.fetch(revokedPda);
+    await program.methods
+      .fileAppeal(toFixedBytes("bafy-revocation-appeal", 64))
+      .accounts({
+        protocol: protocolPda,
+        slashRecord: null,
+        claim: revokedPda,
+        rejection: null,
+        appeal: revokedAppealPda,
+        appellant: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const providerBefore = await program.account.agentAccount.fetch(providerPda);
+    await program.methods
+      .resolveAppeal(true)
+      .accounts({
+        protocol: protocolPda,
+        appeal: revokedAppealPda,
+        agentAccount: providerPda,
+        slashRecord: null,
+        claim: revokedPda,
+        rejection: null,
+        task: null,
+        authority: provider.wallet.publicKey,
+        restoredClaim: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const reinstated = await program.account.claimRecord.fetch(revokedPda);
+    const providerAfter = await program.account.agentAccount.fetch(providerPda);
+    const revokedAppeal = await program.account.appealRecord.fetch(revokedAppealPda);
+    assert.equal(reinstated.status, CLAIM_STATUS_APPROVED);
+    assert.equal(reinstated.clipsClawedBack.toNumber(), 0);
+    assert.equal(
+      revokedAppeal.clipsRestored.toNumber(),
+      revoked.clipsClawedBack.toNumber()
+    );
+    assert.equal(
+      providerAfter.clipsBalance.toNumber(),
+      providerBefore.clipsBalance.toNumber() + revoked.clipsClawedBack.toNumber()
+    );
+  });
+
+  it("Promotes agents up the tier ladder with a cooldown", async () => {
+    const tierConfigPda = PublicKey.findProgramAddressSync(
+      [TIER_CONFIG_SEED],
+      program.programId
+    )[0];
+    const agentPda = getAgentPda(program.programId, agent3.publicKey);
+    const unreachable = new anchor.BN("1000000000000");
+    const minClips = [0, 0, 0].map((v) => new anchor.BN(v)).concat([
+      unreachable,
+      unreachable,
+      unreachable,
+    ]);
+    const setTierConfig = (minTasks: number[], cooldown: number) =>
+      program.methods
+        .setTierConfig(minClips, minTasks, cooldown, [0, 0, 0, 0, 0, 0])
+        .accounts({
+          protocol: protocolPda,
+          tierConfig: tierConfigPda,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    const promote = () =>
+      program.methods
+        .promoteAgent()
+        .accounts({
+          protocol: protocolPda,
+          tierConfig: tierConfigPda,
+          agentAccount: agentPda,
+          agent: agent3.publicKey,
+        })
+        .signers([agent3])
+        .rpc();
+
+    try {
+      await setTierConfig([0, 2, 1, 1000, 1000, 1000], 0);
+      assert.fail("Expected decreasing tier thresholds to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Tier thresholds must not decrease");
+    }
+
+    await setTierConfig([0, 1, 1, 1000, 1000, 1000], 0);
+    await promote();
+    await promote();
+    let agent = await program.account.agentAccount.fetch(agentPda);
+    assert.equal(agent.efficiencyTier, 2);
+    assert.isTrue(agent.lastPromotedAt.gt(new anchor.BN(0)));
+
+    try {
+      await promote();
+      assert.fail("Expected promotion without meeting thresholds to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Agent does not meet the next tier's requirements");
+    }
+
+    await setTierConfig([0, 1, 1, 1, 1, 1], 3600);
+    try {
+      await promote();
+      assert.fail("Expected promotion inside the cooldown to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Promotion cooldown is still active");
+    }
+    agent = await program.account.agentAccount.fetch(agentPda);
+    assert.equal(agent.efficiencyTier, 2);
+  });
+
+  it("Freezes a flagged pending claim until the authority unfreezes it", async () => {
+    const CLAIM_STATUS_APPROVED = 0;
+    const CLAIM_STATUS_PENDING = 1;
+    const optimisticTaskId = 50;
+    const taskPda = getTaskPda(program.programId, optimisticTaskId);
+    const disputeConfigPda = PublicKey.findProgramAddressSync(
+      [DISPUTE_CONFIG_SEED],
+      program.programId
+    )[0];
+    const flaggerPda = getAgentPda(program.programId, agent3.publicKey);
+    const claimantPda = getAgentPda(program.programId, agent4.publicKey);
+    const claimPda = getClaimPda(program.programId, optimisticTaskId, agent4.publicKey);
+    const setFreezeThreshold = (threshold: number) =>
+      program.methods
+        .setDisputeConfig(0, new anchor.BN(10), 5000, false, threshold)
+        .accounts({
+          protocol: protocolPda,
+          disputeConfig: disputeConfigPda,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    const flag = (target: PublicKey) =>
+      program.methods
+        .flagClaim(1, toFixedBytes("bafy-flag-evidence", 64))
+        .accounts({
+          protocol: protocolPda,
+          disputeConfig: disputeConfigPda,
+          claim: target,
+          flag: PublicKey.findProgramAddressSync(
+            [FLAG_SEED, target.toBuffer(), agent3.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          flaggerAgent: flaggerPda,
+          flagger: agent3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent3])
+        .rpc();
+    const finalize = () =>
+      program.methods
+        .finalizeClaim()
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          claim: claimPda,
+          agentAccount: claimantPda,
+        })
+        .rpc();
+
+    await setFreezeThreshold(1);
+
+    // Task 50 has a zero-length window, so only the freeze holds the payout.
+    await program.methods
+      .submitProof(optimisticTaskId, toFixedBytes("bafy-flagged-proof", 64))
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        agentAccount: claimantPda,
+        claim: claimPda,
+        agent: agent4.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent4])
+      .rpc();
+
+    // Tier 2 unlocks community flagging; one flag reaches the threshold.
+    await flag(claimPda);
+    let claim = await program.account.claimRecord.fetch(claimPda);
+    assert.equal(claim.status, CLAIM_STATUS_PENDING);
+    assert.equal(claim.flagCount, 1);
+    assert.isTrue(claim.payoutFrozen);
+
+    try {
+      await finalize();
+      assert.fail("Expected finalize_claim on a frozen claim to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Claim payout is frozen");
+    }
+
+    // Approved instant claims still collect flags, but never freeze.
+    const approvedPda = getClaimPda(program.programId, task1Id, provider.wallet.publicKey);
+    const approvedBefore = await program.account.claimRecord.fetch(approvedPda);
+    await flag(approvedPda);
+    const approved = await program.account.claimRecord.fetch(approvedPda);
+    assert.equal(approved.status, CLAIM_STATUS_APPROVED);
+    assert.equal(approved.flagCount, approvedBefore.flagCount + 1);
+    assert.isFalse(approved.payoutFrozen);
+
+    await program.methods
+      .unfreezeClaim()
+      .accounts({
+        protocol: protocolPda,
+        claim: claimPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const claimantBefore = await program.account.agentAccount.fetch(claimantPda);
+    await finalize();
+    claim = await program.account.claimRecord.fetch(claimPda);
+    assert.equal(claim.status, CLAIM_STATUS_APPROVED);
+    assert.equal(claim.flagCount, 1);
+    const claimant = await program.account.agentAccount.fetch(claimantPda);
+    assert.equal(
+      claimant.clipsBalance.toNumber(),
+      claimantBefore.clipsBalance.toNumber() + 40
+    );
+
+    await setFreezeThreshold(3);
+  });
+
+  it("Decays inactive agents one tier per inactivity window", async () => {
+    const tierConfigPda = PublicKey.findProgramAddressSync(
+      [TIER_CONFIG_SEED],
+      program.programId
+    )[0];
+    const agentPda = getAgentPda(program.programId, agent3.publicKey);
+    const tierConfig = await program.account.tierConfig.fetch(tierConfigPda);
+    const setDecayWindows = (decayAfterSeconds: number[]) =>
+      program.methods
+        .setTierConfig(
+          tierConfig.minClipsBalance,
+          tierConfig.minTasksCompleted,
+          tierConfig.promotionCooldownSeconds,
+          decayAfterSeconds
+        )
+        .accounts({
+          protocol: protocolPda,
+          tierConfig: tierConfigPda,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    const decay = () =>
+      program.methods
+        .decayTier()
+        .accounts({
+          protocol: protocolPda,
+          tierConfig: tierConfigPda,
+          agentAccount: agentPda,
+        })
+        .rpc();
+
+    await setDecayWindows([0, 3600, 3600, 0, 0, 0]);
+    try {
+      await decay();
+      assert.fail("Expected decay inside the inactivity window to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Agent is not due for tier decay");
+    }
+
+    await setDecayWindows([0, 3600, 1, 0, 0, 0]);
+    await new Promise((resolve) => setTimeout(resolve, 2500));
+    await decay();
+
+    const agent = await program.account.agentAccount.fetch(agentPda);
+    assert.equal(agent.efficiencyTier, 1);
+    assert.isTrue(agent.lastDecayedAt.gt(new anchor.BN(0)));
+
+    // The decay restarts the window, and tier 1 has a longer one.
+    try {
+      await decay();
+      assert.fail("Expected a second decay to wait for the next window");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Agent is not due for tier decay");
+    }
+  });
+
+  it("Applies update_economy to new registrations", async () => {
+    const updateEconomy = (airdropBps: number, inviteeBps: number, inviterBps: number) =>
+      program.methods
+        .updateEconomy(baseUnit, airdropBps, inviteeBps, inviterBps)
+        .accounts({
+          protocol: protocolPda,
+          economyConfig: economyConfigPda,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    try {
+      await program.methods
+        .updateEconomy(baseUnit, 1, 0, 0)
+        .accounts({
+          protocol: protocolPda,
+          economyConfig: economyConfigPda,
+          authority: unauthorized.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([unauthorized])
+        .rpc();
+      assert.fail("Expected non-authority update_economy to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Unauthorized");
+    }
+
+    await updateEconomy(25000, 20000, 10000);
+
+    const soloAgent = Keypair.generate();
+    await airdrop(provider, soloAgent.publicKey);
+    const soloPda = getAgentPda(program.programId, soloAgent.publicKey);
+    await program.methods
+      .registerAgent()
+      .accounts({
+        protocol: protocolPda,
+        economyConfig: economyConfigPda,
+        agentAccount: soloPda,
+        agent: soloAgent.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([soloAgent])
+      .rpc();
+    const solo = await program.account.agentAccount.fetch(soloPda);
+    assert.equal(solo.clipsBalance.toNumber(), 250);
+
+    // agent4 created an invite in the freeze test above.
+    const invitee = Keypair.generate();
+    await airdrop(provider, invitee.publicKey);
+    const inviteePda = getAgentPda(program.programId, invitee.publicKey);
+    const inviterPda = getAgentPda(program.programId, agent4.publicKey);
+    const inviterBefore = await program.account.agentAccount.fetch(inviterPda);
+    await program.methods
+      .registerAgentWithInvite(Array.from(agent4.publicKey.toBuffer()))
+      .accounts({
+        protocol: protocolPda,
+        economyConfig: economyConfigPda,
+        agentAccount: inviteePda,
+        inviterAgent: inviterPda,
+        inviteRecord: getInvitePda(program.programId, agent4.publicKey),
+        agent: invitee.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([invitee])
+      .rpc();
+
+    const inviteeAccount = await program.account.agentAccount.fetch(inviteePda);
+    const inviterAfter = await program.account.agentAccount.fetch(inviterPda);
+    assert.equal(inviteeAccount.clipsBalance.toNumber(), 200);
+    assert.equal(
+      inviterAfter.clipsBalance.toNumber(),
+      inviterBefore.clipsBalance.toNumber() + 100
+    );
+
+    await updateEconomy(10000, 15000, 5000);
+  });
+
+  it("Caps emission and halves the base unit per the emission policy", async () => {
+    const emissionPolicyPda = PublicKey.findProgramAddressSync(
+      [EMISSION_POLICY_SEED],
+      program.programId
+    )[0];
+    const setEmissionPolicy = (
+      supplyCap: anchor.BN,
+      epochDuration: number,
+      halvingInterval: number,
+      proRate: boolean
+    ) =>
+      program.methods
+        .setEmissionPolicy(supplyCap, new anchor.BN(0), epochDuration, halvingInterval, proRate)
+        .accounts({
+          protocol: protocolPda,
+          emissionPolicy: emissionPolicyPda,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    const register = async (emissionPolicy: PublicKey | null) => {
+      const wallet = Keypair.generate();
+      await airdrop(provider, wallet.publicKey);
+      const agentPda = getAgentPda(program.programId, wallet.publicKey);
+      await program.methods
+        .registerAgent()
+        .accounts({
+          protocol: protocolPda,
+          economyConfig: economyConfigPda,
+          emissionPolicy,
+          agentAccount: agentPda,
+          agent: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+      return program.account.agentAccount.fetch(agentPda);
+    };
+
+    // Leave room for one full 100 Clips airdrop and half of another.
+    const before = await program.account.protocolState.fetch(protocolPda);
+    const cap = before.totalClipsDistributed.add(new anchor.BN(150));
+    await setEmissionPolicy(cap, 86400, 0, false);
+
+    try {
+      await register(null);
+      assert.fail("Expected registration without the emission policy to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Emission policy account is required");
+    }
+
+    let agent = await register(emissionPolicyPda);
+    assert.equal(agent.clipsBalance.toNumber(), 100);
+
+    try {
+      await register(emissionPolicyPda);
+      assert.fail("Expected registration past the supply cap to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Clips supply cap or epoch budget is exhausted");
+    }
+
+    await setEmissionPolicy(cap, 86400, 0, true);
+    agent = await register(emissionPolicyPda);
+    assert.equal(agent.clipsBalance.toNumber(), 50);
+    let protocol = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(protocol.totalClipsDistributed.toString(), cap.toString());
+
+    // Pending claims count against the cap from submission: with 60 Clips of
+    // headroom, a pending 40 Clips claim leaves no room for a second one.
+    const optimisticTaskId = 50;
+    const taskPda = getTaskPda(program.programId, optimisticTaskId);
+    const setChallengeWindow = (seconds: number) =>
+      program.methods
+        .setTaskVerification(optimisticTaskId, 1, seconds)
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+    const submitOptimistic = (wallet: Keypair | null) => {
+      const agentKey = wallet ? wallet.publicKey : provider.wallet.publicKey;
+      const call = program.methods
+        .submitProof(optimisticTaskId, toFixedBytes("bafy-capped-proof", 64))
+        .accounts({
+          protocol: protocolPda,
+          emissionPolicy: emissionPolicyPda,
+          task: taskPda,
+          agentAccount: getAgentPda(program.programId, agentKey),
+          claim: getClaimPda(program.programId, optimisticTaskId, agentKey),
+          agent: agentKey,
+          systemProgram: SystemProgram.programId,
+        });
+      return wallet ? call.signers([wallet]).rpc() : call.rpc();
+    };
+
+    const raisedCap = cap.add(new anchor.BN(60));
+    await setEmissionPolicy(raisedCap, 86400, 0, false);
+    await setChallengeWindow(3600);
+    await submitOptimistic(agent3);
+    let policy = await program.account.emissionPolicy.fetch(emissionPolicyPda);
+    protocol = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(policy.totalAllocated.toString(), cap.add(new anchor.BN(40)).toString());
+    assert.equal(protocol.totalClipsDistributed.toString(), cap.toString());
+
+    try {
+      await submitOptimistic(null);
+      assert.fail("Expected a second pending claim past the cap to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Clips supply cap or epoch budget is exhausted");
+    }
+
+    // Rejecting the pending claim hands its 40 Clips back.
+    const agent3Pda = getAgentPda(program.programId, agent3.publicKey);
+    const rejectAgent3Claim = async () => {
+      const agent3Account = await program.account.agentAccount.fetch(agent3Pda);
+      await program.methods
+        .rejectClaim()
+        .accounts({
+          protocol: protocolPda,
+          emissionPolicy: emissionPolicyPda,
+          task: taskPda,
+          claim: getClaimPda(program.programId, optimisticTaskId, agent3.publicKey),
+          agentAccount: agent3Pda,
+          rejection: getRejectionPda(
+            program.programId,
+            agent3.publicKey,
+            agent3Account.rejectionCount
+          ),
+          agent: agent3.publicKey,
+          reviewer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    };
+    await rejectAgent3Claim();
+    policy = await program.account.emissionPolicy.fetch(emissionPolicyPda);
+    assert.equal(policy.totalAllocated.toString(), cap.toString());
+
+    await setChallengeWindow(0);
+    await submitOptimistic(null);
+    await program.methods
+      .finalizeClaim()
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        claim: getClaimPda(program.programId, optimisticTaskId, provider.wallet.publicKey),
+        agentAccount: getAgentPda(program.programId, provider.wallet.publicKey),
+      })
+      .rpc();
+    protocol = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(
+      protocol.totalClipsDistributed.toString(),
+      cap.add(new anchor.BN(40)).toString()
+    );
+
+    // A claim booked under day-long epochs is still released after switching
+    // to one-second epochs moves genesis_at past its submission.
+    await setEmissionPolicy(new anchor.BN(0), 86400, 0, false);
+    await setChallengeWindow(3600);
+    await submitOptimistic(agent3);
+    await new Promise((resolve) => setTimeout(resolve, 1500));
+    // One-second epochs with a halving every epoch.
+    await setEmissionPolicy(new anchor.BN(0), 1, 1, false);
+    const booked = await program.account.emissionPolicy.fetch(emissionPolicyPda);
+    await rejectAgent3Claim();
+    policy = await program.account.emissionPolicy.fetch(emissionPolicyPda);
+    assert.equal(
+      policy.totalAllocated.toString(),
+      booked.totalAllocated.sub(new anchor.BN(40)).toString()
+    );
+    await setChallengeWindow(0);
+
+    await new Promise((resolve) => setTimeout(resolve, 2500));
+    agent = await register(emissionPolicyPda);
+    protocol = await program.account.protocolState.fetch(protocolPda);
+    policy = await program.account.emissionPolicy.fetch(emissionPolicyPda);
+    assert.isAbove(policy.halvingsApplied, 0);
+    assert.equal(
+      protocol.baseRewardUnit.toNumber(),
+      Math.floor(100 / 2 ** policy.halvingsApplied)
+    );
+    // The airdrop is a share of the base unit, so it halves too.
+    assert.equal(agent.clipsBalance.toNumber(), protocol.baseRewardUnit.toNumber());
+
+    // Later tests run uncapped with the original base unit. Going back to
+    // day-long epochs keeps the epoch count and restarts the current epoch now.
+    await setEmissionPolicy(new anchor.BN(0), 86400, 0, false);
+    const rebased = await program.account.emissionPolicy.fetch(emissionPolicyPda);
+    assert.isAtLeast(rebased.currentEpoch, policy.currentEpoch);
+    const epochStart = rebased.genesisAt.toNumber() + rebased.currentEpoch * 86400;
+    assert.isAtMost(Math.abs(epochStart - Math.floor(Date.now() / 1000)), 30);
+    await program.methods
+      .updateEconomy(baseUnit, 10000, 15000, 5000)
+      .accounts({
+        protocol: protocolPda,
+        economyConfig: economyConfigPda,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("Resolves base-unit task rewards at submission time", async () => {
+    const REWARD_MODE_BASE_UNITS = 1;
+    const baseUnitTaskId = 60;
+    const taskPda = getTaskPda(program.programId, baseUnitTaskId);
+    const emissionPolicyPda = PublicKey.findProgramAddressSync(
+      [EMISSION_POLICY_SEED],
+      program.programId
+    )[0];
+    const updateBaseUnit = (base: number) =>
+      program.methods
+        .updateEconomy(new anchor.BN(base), 10000, 15000, 5000)
+        .accounts({
+          protocol: protocolPda,
+          economyConfig: economyConfigPda,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    const submit = async (agent: Keypair) => {
+      const claimPda = getClaimPda(program.programId, baseUnitTaskId, agent.publicKey);
+      await program.methods
+        .submitProof(baseUnitTaskId, toFixedBytes("bafy-base-unit-proof", 64))
+        .accounts({
+          protocol: protocolPda,
+          emissionPolicy: emissionPolicyPda,
+          task: taskPda,
+          agentAccount: getAgentPda(program.programId, agent.publicKey),
+          claim: claimPda,
+          agent: agent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+      return program.account.claimRecord.fetch(claimPda);
+    };
+
+    await program.methods
+      .createTask(
+        baseUnitTaskId,
+        toFixedBytes("Base Unit Task", 32),
+        toFixedBytes("bafy-base-unit-task", 64),
+        new anchor.BN(10),
+        5,
+        0,
+        NO_PREREQ_TASK_ID
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: taskPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    try {
+      await program.methods
+        .setTaskRewardMode(baseUnitTaskId, 7, 15000)
+        .accounts({
+          protocol: protocolPda,
+          task: taskPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+      assert.fail("Expected an unknown reward mode to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Invalid reward mode");
+    }
+
+    await program.methods
+      .setTaskRewardMode(baseUnitTaskId, REWARD_MODE_BASE_UNITS, 15000)
+      .accounts({
+        protocol: protocolPda,
+        task: taskPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    // 1.5 base units at a base unit of 100.
+    let claim = await submit(agent2);
+    assert.equal(claim.clipsAwarded.toNumber(), 150);
+
+    // Halving the base unit halves the payout without touching the task.
+    await updateBaseUnit(50);
+    claim = await submit(agent3);
+    assert.equal(claim.clipsAwarded.toNumber(), 75);
+    const task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.rewardClips.toNumber(), 10);
+
+    await updateBaseUnit(100);
+  });
+
+  it("Decays task rewards along per-claim and time-based curves", async () => {
+    const REWARD_CURVE_LINEAR = 1;
+    const REWARD_CURVE_EXPONENTIAL = 2;
+    const REWARD_CURVE_BY_CLAIMS = 0;
+    const REWARD_CURVE_BY_TIME = 1;
+    const curveTaskId = 61;
+    const timedCurveTaskId = 64;
+    const taskPda = getTaskPda(program.programId, curveTaskId);
+    const emissionPolicyPda = PublicKey.findProgramAddressSync(
+      [EMISSION_POLICY_SEED],
+      program.programId
+    )[0];
+    const submit = async (agent: Keypair, taskId = curveTaskId) => {
+      const claimPda = getClaimPda(program.programId, taskId, agent.publicKey);
+      await program.methods
+        .submitProof(taskId, toFixedBytes("bafy-curve-proof", 64))
+        .accounts({
+          protocol: protocolPda,
+          emissionPolicy: emissionPolicyPda,
+          task: getTaskPda(program.programId, taskId),
+          agentAccount: getAgentPda(program.programId, agent.publicKey),
+          claim: claimPda,
+          agent: agent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+      return program.account.claimRecord.fetch(claimPda);
+    };
+    const setCurve = (taskId: number, curve: number, basis: number, period: number) =>
+      program.methods
+        .setTaskRewardCurve(taskId, curve, basis, 5000, period)
+        .accounts({
+          protocol: protocolPda,
+          task: getTaskPda(program.programId, taskId),
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    for (const taskId of [curveTaskId, timedCurveTaskId]) {
+      await program.methods
+        .createTask(
+          taskId,
+          toFixedBytes("Early Bird Task", 32),
+          toFixedBytes("bafy-early-bird-task", 64),
+          new anchor.BN(100),
+          5,
+          0,
+          NO_PREREQ_TASK_ID
+        )
+        .accounts({
+          protocol: protocolPda,
+          authority: provider.wallet.publicKey,
+          task: getTaskPda(program.programId, taskId),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    try {
+      await setCurve(curveTaskId, REWARD_CURVE_LINEAR, REWARD_CURVE_BY_CLAIMS, 0);
+      assert.fail("Expected a zero curve period to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Invalid reward curve");
+    }
+
+    // Linear from 100 Clips down to a 50% floor over two claims.
+    await setCurve(curveTaskId, REWARD_CURVE_LINEAR, REWARD_CURVE_BY_CLAIMS, 2);
+
+    let claim = await submit(agent2);
+    assert.equal(claim.clipsAwarded.toNumber(), 100);
+    claim = await submit(agent3);
+    assert.equal(claim.clipsAwarded.toNumber(), 75);
+
+    let task = await program.account.taskRecord.fetch(taskPda);
+    assert.equal(task.currentClaims, 2);
+    assert.equal(task.rewardFloorBps, 5000);
+
+    // Exponential, halving the 50 Clips above the floor once per claim: two
+    // prior claims leave a quarter of it.
+    await setCurve(curveTaskId, REWARD_CURVE_EXPONENTIAL, REWARD_CURVE_BY_CLAIMS, 1);
+    claim = await submit(agent4);
+    assert.equal(claim.clipsAwarded.toNumber(), 50 + (50 >> 2));
+
+    // Exponential by time, halving once per full second since the task was
+    // created.
+    await setCurve(timedCurveTaskId, REWARD_CURVE_EXPONENTIAL, REWARD_CURVE_BY_TIME, 1);
+    await new Promise((resolve) => setTimeout(resolve, 1500));
+    claim = await submit(agent2, timedCurveTaskId);
+    task = await program.account.taskRecord.fetch(getTaskPda(program.programId, timedCurveTaskId));
+    const halvings = claim.completedAt.toNumber() - task.createdAt.toNumber();
+    assert.isAbove(halvings, 0);
+    assert.equal(claim.clipsAwarded.toNumber(), 50 + Math.floor(50 / 2 ** halvings));
+  });
+
+  it("Boosts rewards during a reward event", async () => {
+    const eventTaskId = 62;
+    const taskPda = getTaskPda(program.programId, eventTaskId);
+    const emissionPolicyPda = PublicKey.findProgramAddressSync(
+      [EMISSION_POLICY_SEED],
+      program.programId
+    )[0];
+    const getRewardEventPda = (eventId: number) =>
+      PublicKey.findProgramAddressSync(
+        [REWARD_EVENT_SEED, new anchor.BN(eventId).toArrayLike(Buffer, "le", 4)],
+        program.programId
+      )[0];
+    const setRewardEvent = (eventId: number, startsAt: number, endsAt: number) =>
+      program.methods
+        .setRewardEvent(
+          eventId,
+          new anchor.BN(startsAt),
+          new anchor.BN(endsAt),
+          20000,
+          eventTaskId,
+          eventTaskId
+        )
+        .accounts({
+          protocol: protocolPda,
+          rewardEvent: getRewardEventPda(eventId),
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    const submit = (agent: Keypair, rewardEvent: PublicKey | null) =>
+      program.methods
+        .submitProof(eventTaskId, toFixedBytes("bafy-event-proof", 64))
+        .accounts({
+          protocol: protocolPda,
+          emissionPolicy: emissionPolicyPda,
+          rewardEvent,
+          task: taskPda,
+          agentAccount: getAgentPda(program.programId, agent.publicKey),
+          claim: getClaimPda(program.programId, eventTaskId, agent.publicKey),
+          agent: agent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+    await program.methods
+      .createTask(
+        eventTaskId,
+        toFixedBytes("Launch Weekend Task", 32),
+        toFixedBytes("bafy-launch-weekend-task", 64),
+        new anchor.BN(40),
+        5,
+        0,
+        NO_PREREQ_TASK_ID
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: taskPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const now = Math.floor(Date.now() / 1000);
+    try {
+      await setRewardEvent(1, now, now);
+      assert.fail("Expected an empty event window to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Reward event window, multiplier or task range is invalid");
+    }
+
+    await setRewardEvent(1, now - 600, now + 3600);
+    await setRewardEvent(2, now - 1200, now - 600);
+
+    await submit(agent2, getRewardEventPda(1));
+    let claim = await program.account.claimRecord.fetch(
+      getClaimPda(program.programId, eventTaskId, agent2.publicKey)
+    );
+    assert.equal(claim.clipsAwarded.toNumber(), 80);
+    assert.equal(claim.rewardBoostBps, 20000);
+
+    try {
+      await submit(agent3, getRewardEventPda(2));
+      assert.fail("Expected an ended reward event to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Reward event does not apply to this submission");
+    }
+
+    await submit(agent3, null);
+    claim = await program.account.claimRecord.fetch(
+      getClaimPda(program.programId, eventTaskId, agent3.publicKey)
+    );
+    assert.equal(claim.clipsAwarded.toNumber(), 40);
+    assert.equal(claim.rewardBoostBps, 0);
+  });
+
+  it("Scales task rewards by the agent's tier multiplier", async () => {
+    const tierTaskId = 63;
+    const taskPda = getTaskPda(program.programId, tierTaskId);
+    const agent3Pda = getAgentPda(program.programId, agent3.publicKey);
+    const claimPda = getClaimPda(program.programId, tierTaskId, agent3.publicKey);
+    const emissionPolicyPda = PublicKey.findProgramAddressSync(
+      [EMISSION_POLICY_SEED],
+      program.programId
+    )[0];
+    const setTierMultipliers = (multipliers: number[]) =>
+      program.methods
+        .setTierMultipliers(multipliers)
+        .accounts({
+          protocol: protocolPda,
+          economyConfig: economyConfigPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    try {
+      await setTierMultipliers([10000, 0, 10000, 10000, 10000, 10000]);
+      assert.fail("Expected a zero tier multiplier to fail");
+    } catch (err) {
+      const message = (err as Error).toString();
+      assert.include(message, "Tier reward multipliers must be greater than zero");
+    }
+
+    await setTierMultipliers([10000, 15000, 20000, 25000, 30000, 40000]);
+
+    await program.methods
+      .createTask(
+        tierTaskId,
+        toFixedBytes("Tier Scaled Task", 32),
+        toFixedBytes("bafy-tier-scaled-task", 64),
+        new anchor.BN(40),
+        5,
+        0,
+        NO_PREREQ_TASK_ID
+      )
+      .accounts({
+        protocol: protocolPda,
+        authority: provider.wallet.publicKey,
+        task: taskPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const agentBefore = await program.account.agentAccount.fetch(agent3Pda);
+    const protocolBefore = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(agentBefore.efficiencyTier, 1);
+
+    await program.methods
+      .submitProof(tierTaskId, toFixedBytes("bafy-tier-scaled-proof", 64))
+      .accounts({
+        protocol: protocolPda,
+        emissionPolicy: emissionPolicyPda,
+        economyConfig: economyConfigPda,
+        task: taskPda,
+        agentAccount: agent3Pda,
+        claim: claimPda,
+        agent: agent3.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent3])
+      .rpc();
+
+    const claim = await program.account.claimRecord.fetch(claimPda);
+    const agentAfter = await program.account.agentAccount.fetch(agent3Pda);
+    const protocolAfter = await program.account.protocolState.fetch(protocolPda);
+    assert.equal(claim.clipsAwarded.toNumber(), 60);
+    assert.equal(agentAfter.clipsBalance.sub(agentBefore.clipsBalance).toNumber(), 60);
+    assert.equal(
+      protocolAfter.totalClipsDistributed.sub(protocolBefore.totalClipsDistributed).toNumber(),
+      60
+    );
+
+    await setTierMultipliers([10000, 10000, 10000, 10000, 10000, 10000]);
+  });
 });